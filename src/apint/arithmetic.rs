@@ -1,12 +1,14 @@
 use apint::{ApInt};
 use apint::utils::ZipDataAccessMut::{Inl, Ext};
+use apint::utils::{DataAccessMut};
 use traits::{Width};
 use errors::{DivOp, Error, Result};
+use digit;
 use digit::{Digit, DigitRepr};
 use ll;
 use utils::{try_forward_bin_mut_impl, forward_mut_impl};
 
-use std::ops::{
+use core::ops::{
 	Neg,
 	Add,
 	Sub,
@@ -16,6 +18,149 @@ use std::ops::{
 	MulAssign
 };
 
+// ============================================================================
+//  Multi-digit division helpers
+// ----------------------------------------------------------------------------
+//
+//  These free functions implement restoring binary long division directly
+//  over little-endian `Digit` slices, as used by the `Ext` arms of the
+//  `udiv`/`sdiv`/`urem`/`srem` family below.
+// ============================================================================
+
+/// Returns `true` if the bit at `width - 1` is set within `digits`.
+fn digits_sign_bit(digits: &[Digit], width: usize) -> bool {
+	let idx = (width - 1) / digit::BITS;
+	let bit = (width - 1) % digit::BITS;
+	(digits[idx].repr() >> bit) & 0x1 == 0x1
+}
+
+/// Zeroes out all bits at or above `width` within `digits`.
+fn digits_mask_to_width(digits: &mut [Digit], width: usize) {
+	let idx = width / digit::BITS;
+	let bit = width % digit::BITS;
+	let first_cleared = if bit != 0 {
+		let mask = ((1 as DigitRepr) << bit) - 1;
+		digits[idx] = Digit(digits[idx].repr() & mask);
+		idx + 1
+	} else {
+		idx
+	};
+	for d in digits[first_cleared..].iter_mut() {
+		*d = Digit::zero();
+	}
+}
+
+/// Negates `digits` (two's-complement) as a value of the given bit `width`.
+fn digits_negate(digits: &mut [Digit], width: usize) {
+	for d in digits.iter_mut() {
+		*d = Digit(!d.repr());
+	}
+	let mut carry = Digit::one();
+	for d in digits.iter_mut() {
+		*d = ll::carry_add(*d, Digit::zero(), &mut carry);
+	}
+	digits_mask_to_width(digits, width);
+}
+
+/// Returns `true` if `lhs >= rhs` when both are interpreted as unsigned,
+/// equal-length little-endian digit buffers.
+fn digits_uge(lhs: &[Digit], rhs: &[Digit]) -> bool {
+	for i in (0 .. lhs.len()).rev() {
+		if lhs[i].repr() != rhs[i].repr() {
+			return lhs[i].repr() > rhs[i].repr()
+		}
+	}
+	true
+}
+
+/// Subtracts `rhs` from `lhs` inplace, both equal-length little-endian digit buffers.
+fn digits_sub_assign(lhs: &mut [Digit], rhs: &[Digit]) {
+	let mut borrow = Digit::zero();
+	for (l, r) in lhs.iter_mut().zip(rhs) {
+		*l = ll::borrow_sub(*l, *r, &mut borrow);
+	}
+}
+
+/// Shifts `digits` left by one bit inplace, losing the top-most bit.
+fn digits_shl_one(digits: &mut [Digit]) {
+	let mut carry: DigitRepr = 0;
+	for d in digits.iter_mut() {
+		let repr = d.repr();
+		let next_carry = repr >> (digit::BITS - 1);
+		*d = Digit((repr << 1) | carry);
+		carry = next_carry;
+	}
+}
+
+/// Returns `true` if the bit at position `n` is set within `digits`.
+fn digits_get_bit(digits: &[Digit], n: usize) -> bool {
+	(digits[n / digit::BITS].repr() >> (n % digit::BITS)) & 0x1 == 0x1
+}
+
+/// Sets the bit at position `n` within `digits`.
+fn digits_set_bit(digits: &mut [Digit], n: usize) {
+	let idx = n / digit::BITS;
+	let bit = n % digit::BITS;
+	digits[idx] = Digit(digits[idx].repr() | (1 << bit));
+}
+
+/// Hardware-accelerated carry/borrow propagation for the `Ext` ripple loops
+/// of `checked_add_assign`/`checked_sub_assign`.
+///
+/// Threads the CPU carry flag directly through `_addcarry_u64`/`_subborrow_u64`
+/// (the `x86`/`x86_64` `adc`/`sbb` instructions) instead of widening each
+/// digit pair into a `u128`, mirroring num-bigint's `use_addcarry` cfg. Only
+/// available with the `use_addcarry` feature enabled on `x86`/`x86_64`
+/// targets; everywhere else the portable `ll::carry_add`/`ll::borrow_sub`
+/// loop is used instead, with identical observable results.
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "use_addcarry"))]
+mod addcarry {
+	use digit::{Digit, DigitRepr};
+
+	#[cfg(target_arch = "x86")]
+	use core::arch::x86::{_addcarry_u64, _subborrow_u64};
+	#[cfg(target_arch = "x86_64")]
+	use core::arch::x86_64::{_addcarry_u64, _subborrow_u64};
+
+	/// Adds `a + b + carry_in` via the hardware carry flag, returning the
+	/// resulting digit and the carry-out flag.
+	#[inline]
+	pub(super) fn carry_add(a: Digit, b: Digit, carry_in: u8) -> (Digit, u8) {
+		let mut out: DigitRepr = 0;
+		let carry_out = unsafe { _addcarry_u64(carry_in, a.repr(), b.repr(), &mut out) };
+		(Digit(out), carry_out)
+	}
+
+	/// Subtracts `a - b - borrow_in` via the hardware borrow flag, returning
+	/// the resulting digit and the borrow-out flag.
+	#[inline]
+	pub(super) fn borrow_sub(a: Digit, b: Digit, borrow_in: u8) -> (Digit, u8) {
+		let mut out: DigitRepr = 0;
+		let borrow_out = unsafe { _subborrow_u64(borrow_in, a.repr(), b.repr(), &mut out) };
+		(Digit(out), borrow_out)
+	}
+}
+
+/// Unsigned restoring long division: returns `(quotient, remainder)` for
+/// `dividend / divisor` and `dividend % divisor`, both equal-length
+/// little-endian digit buffers.
+fn digits_udivmod(dividend: &[Digit], divisor: &[Digit]) -> (Vec<Digit>, Vec<Digit>) {
+	let len = dividend.len();
+	let mut quotient = vec![Digit::zero(); len];
+	let mut remainder = vec![Digit::zero(); len];
+	for i in (0 .. len * digit::BITS).rev() {
+		digits_shl_one(&mut remainder);
+		if digits_get_bit(dividend, i) {
+			digits_set_bit(&mut remainder, 0);
+		}
+		if digits_uge(&remainder, divisor) {
+			digits_sub_assign(&mut remainder, divisor);
+			digits_set_bit(&mut quotient, i);
+		}
+	}
+	(quotient, remainder)
+}
+
 /// # Arithmetic Operations
 impl ApInt {
 
@@ -27,18 +172,57 @@ impl ApInt {
 	}
 
 	/// Negates this `ApInt` inplace.
-	/// 
+	///
 	/// **Note:** This will **not** allocate memory.
 	pub fn negate(&mut self) {
-		let width = self.width();
 		self.bitnot();
-		// self.increment_by(1); // This is not implemented, yet.
-		                         // Replace `self.checked_add_assign(..)` with this
-		                         // as soon as possible for avoiding temporary
-		                         // expensive copies of `self`.
-		self.checked_add_assign(&ApInt::one(width))
-			.expect("This operation cannot fail since the temporary `ApInt`\
-						and `self` are ensured to always have the same bit width.");
+		self.increment_by(1);
+		self.clear_unused_bits();
+	}
+
+	/// Increments `self` by the given `amount` inplace.
+	///
+	/// **Note:** This will **not** allocate memory and cannot fail since the
+	/// result simply wraps around on overflow, consistent with the rest of
+	/// this crate's modular arithmetic semantics.
+	pub fn increment_by(&mut self, amount: u64) {
+		match self.access_data_mut() {
+			DataAccessMut::Inl(digit) => {
+				*digit.repr_mut() = digit.repr().wrapping_add(amount as DigitRepr);
+			}
+			DataAccessMut::Ext(digits) => {
+				let mut carry = Digit(amount as DigitRepr);
+				for d in digits.iter_mut() {
+					*d = ll::carry_add(*d, Digit::zero(), &mut carry);
+					if carry.repr() == 0 {
+						break
+					}
+				}
+			}
+		}
+		self.clear_unused_bits();
+	}
+
+	/// Decrements `self` by the given `amount` inplace.
+	///
+	/// **Note:** This will **not** allocate memory and cannot fail since the
+	/// result simply wraps around on underflow, consistent with the rest of
+	/// this crate's modular arithmetic semantics.
+	pub fn decrement_by(&mut self, amount: u64) {
+		match self.access_data_mut() {
+			DataAccessMut::Inl(digit) => {
+				*digit.repr_mut() = digit.repr().wrapping_sub(amount as DigitRepr);
+			}
+			DataAccessMut::Ext(digits) => {
+				let mut borrow = Digit(amount as DigitRepr);
+				for d in digits.iter_mut() {
+					*d = ll::borrow_sub(*d, Digit::zero(), &mut borrow);
+					if borrow.repr() == 0 {
+						break
+					}
+				}
+			}
+		}
 		self.clear_unused_bits();
 	}
 
@@ -69,9 +253,21 @@ impl ApInt {
 				*lhs = Digit(result);
 			}
 			Ext(lhs, rhs) => {
-				let mut carry = Digit::zero();
-				for (l, r) in lhs.into_iter().zip(rhs) {
-					*l = ll::carry_add(*l, *r, &mut carry);
+				#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "use_addcarry"))]
+				{
+					let mut carry = 0_u8;
+					for (l, r) in lhs.into_iter().zip(rhs) {
+						let (sum, carry_out) = addcarry::carry_add(*l, *r, carry);
+						*l = sum;
+						carry = carry_out;
+					}
+				}
+				#[cfg(not(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "use_addcarry")))]
+				{
+					let mut carry = Digit::zero();
+					for (l, r) in lhs.into_iter().zip(rhs) {
+						*l = ll::carry_add(*l, *r, &mut carry);
+					}
 				}
 			}
 		}
@@ -113,9 +309,21 @@ impl ApInt {
 				*lhs = Digit(result);
 			}
 			Ext(lhs, rhs) => {
-				let mut borrow = Digit::zero();
-				for (l, r) in lhs.into_iter().zip(rhs) {
-					*l = ll::borrow_sub(*l, *r, &mut borrow);
+				#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "use_addcarry"))]
+				{
+					let mut borrow = 0_u8;
+					for (l, r) in lhs.into_iter().zip(rhs) {
+						let (diff, borrow_out) = addcarry::borrow_sub(*l, *r, borrow);
+						*l = diff;
+						borrow = borrow_out;
+					}
+				}
+				#[cfg(not(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "use_addcarry")))]
+				{
+					let mut borrow = Digit::zero();
+					for (l, r) in lhs.into_iter().zip(rhs) {
+						*l = ll::borrow_sub(*l, *r, &mut borrow);
+					}
 				}
 			}
 		}
@@ -124,6 +332,104 @@ impl ApInt {
 		Ok(())
 	}
 
+	/// Adds `rhs` to `self` and returns both the result and whether the
+	/// addition overflowed the most-significant used bit.
+	///
+	/// **Note:** This will **not** allocate memory.
+	///
+	/// # Errors
+	///
+	/// - If `self` and `rhs` have unmatching bit widths.
+	pub fn into_overflowing_add(self, rhs: &ApInt) -> Result<(ApInt, bool)> {
+		let mut this = self;
+		let overflow = this.overflowing_add_assign(rhs)?;
+		Ok((this, overflow))
+	}
+
+	/// Add-assigns `rhs` to `self` inplace and returns whether the addition
+	/// overflowed the most-significant used bit.
+	///
+	/// This is the carry out of bit `width - 1`, i.e. the overflow flag of an
+	/// **unsigned** addition. It is not the signed overflow flag: at 8 bits,
+	/// `127 + 1` overflows as signed but does not carry out.
+	///
+	/// **Note:** This will **not** allocate memory.
+	///
+	/// # Errors
+	///
+	/// - If `self` and `rhs` have unmatching bit widths.
+	pub fn overflowing_add_assign(&mut self, rhs: &ApInt) -> Result<bool> {
+		let width = self.width().to_usize();
+		let overflow = match self.zip_access_data_mut(rhs)? {
+			Inl(lhs, rhs) => {
+				let wide = (lhs.repr() as u128) + (rhs.repr() as u128);
+				*lhs = Digit(wide as DigitRepr);
+				(wide >> width) & 1 == 1
+			}
+			Ext(lhs, rhs) => {
+				let mut carry = Digit::zero();
+				for (l, r) in lhs.iter_mut().zip(rhs.iter()) {
+					*l = ll::carry_add(*l, *r, &mut carry);
+				}
+				if width == lhs.len() * digit::BITS {
+					carry.repr() != 0
+				} else {
+					digits_get_bit(lhs, width)
+				}
+			}
+		};
+		self.clear_unused_bits();
+		Ok(overflow)
+	}
+
+	/// Subtracts `rhs` from `self` and returns both the result and whether
+	/// the subtraction overflowed (borrowed out of) the most-significant
+	/// used bit.
+	///
+	/// **Note:** This will **not** allocate memory.
+	///
+	/// # Errors
+	///
+	/// - If `self` and `rhs` have unmatching bit widths.
+	pub fn into_overflowing_sub(self, rhs: &ApInt) -> Result<(ApInt, bool)> {
+		let mut this = self;
+		let overflow = this.overflowing_sub_assign(rhs)?;
+		Ok((this, overflow))
+	}
+
+	/// Subtract-assigns `rhs` from `self` inplace and returns whether the
+	/// subtraction borrowed out of the most-significant used bit.
+	///
+	/// **Note:** This will **not** allocate memory.
+	///
+	/// # Errors
+	///
+	/// - If `self` and `rhs` have unmatching bit widths.
+	pub fn overflowing_sub_assign(&mut self, rhs: &ApInt) -> Result<bool> {
+		let width = self.width().to_usize();
+		let overflow = match self.zip_access_data_mut(rhs)? {
+			Inl(lhs, rhs) => {
+				let (lval, rval) = (lhs.repr(), rhs.repr());
+				let borrow = lval < rval;
+				*lhs = Digit(lval.wrapping_sub(rval));
+				borrow
+			}
+			Ext(lhs, rhs) => {
+				let mut borrow = Digit::zero();
+				for (l, r) in lhs.iter_mut().zip(rhs.iter()) {
+					*l = ll::borrow_sub(*l, *r, &mut borrow);
+				}
+				if width == lhs.len() * digit::BITS {
+					borrow.repr() != 0
+				} else {
+					digits_get_bit(lhs, width)
+				}
+			}
+		};
+		self.clear_unused_bits();
+		Ok(overflow)
+	}
+
 	/// Multiplies `rhs` with `self` and returns the result.
 	/// 
 	/// # Note
@@ -156,8 +462,34 @@ impl ApInt {
 				let result = lval.wrapping_mul(rval);
 				*lhs = Digit(result);
 			}
-			Ext(_lhs, _rhs) => {
-				unimplemented!()
+			Ext(lhs, rhs) => {
+				// Schoolbook long multiplication over the digit slices. The
+				// product of an `lhs.len()` by `rhs.len()` digit number needs
+				// at most `lhs.len() + rhs.len()` digits, so accumulate into
+				// a temporary buffer of that size before truncating back
+				// down to `self`'s own digit count.
+				let mut acc = vec![Digit::zero(); lhs.len() + rhs.len()];
+				for (i, l) in lhs.iter().enumerate() {
+					if l.repr() == 0 {
+						continue
+					}
+					let mut carry: DigitRepr = 0;
+					for (j, r) in rhs.iter().enumerate() {
+						let wide = (l.repr() as u128) * (r.repr() as u128)
+							+ (acc[i + j].repr() as u128)
+							+ (carry as u128);
+						acc[i + j] = Digit(wide as DigitRepr);
+						carry = (wide >> 64) as DigitRepr;
+					}
+					let mut k = i + rhs.len();
+					while carry != 0 {
+						let wide = (acc[k].repr() as u128) + (carry as u128);
+						acc[k] = Digit(wide as DigitRepr);
+						carry = (wide >> 64) as DigitRepr;
+						k += 1;
+					}
+				}
+				lhs.iter_mut().zip(acc.into_iter()).for_each(|(l, a)| *l = a);
 			}
 		}
 		self.clear_unused_bits();
@@ -202,8 +534,9 @@ impl ApInt {
 				let result = lval.wrapping_div(rval);
 				*lhs = Digit(result);
 			}
-			Ext(_lhs, _rhs) => {
-				unimplemented!()
+			Ext(lhs, rhs) => {
+				let (quotient, _remainder) = digits_udivmod(lhs, rhs);
+				lhs.copy_from_slice(&quotient);
 			}
 		}
 		Ok(())
@@ -252,8 +585,23 @@ impl ApInt {
 				let result = lval.wrapping_div(rval) as DigitRepr;
 				*lhs = Digit(result);
 			}
-			Ext(_lhs, _rhs) => {
-				unimplemented!()
+			Ext(lhs, rhs) => {
+				let width = width.to_usize();
+				let lhs_neg = digits_sign_bit(lhs, width);
+				let rhs_neg = digits_sign_bit(rhs, width);
+				let mut lhs_abs = lhs.to_vec();
+				if lhs_neg {
+					digits_negate(&mut lhs_abs, width);
+				}
+				let mut rhs_abs = rhs.to_vec();
+				if rhs_neg {
+					digits_negate(&mut rhs_abs, width);
+				}
+				let (mut quotient, _remainder) = digits_udivmod(&lhs_abs, &rhs_abs);
+				if lhs_neg != rhs_neg {
+					digits_negate(&mut quotient, width);
+				}
+				lhs.copy_from_slice(&quotient);
 			}
 		}
 		self.clear_unused_bits();
@@ -297,8 +645,9 @@ impl ApInt {
 				let result = lval.wrapping_rem(rval);
 				*lhs = Digit(result);
 			}
-			Ext(_lhs, _rhs) => {
-				unimplemented!()
+			Ext(lhs, rhs) => {
+				let (_quotient, remainder) = digits_udivmod(lhs, rhs);
+				lhs.copy_from_slice(&remainder);
 			}
 		}
 		Ok(())
@@ -346,8 +695,23 @@ impl ApInt {
 				let result = lval.wrapping_rem(rval) as DigitRepr;
 				*lhs = Digit(result);
 			}
-			Ext(_lhs, _rhs) => {
-				unimplemented!()
+			Ext(lhs, rhs) => {
+				let width = width.to_usize();
+				let lhs_neg = digits_sign_bit(lhs, width);
+				let rhs_neg = digits_sign_bit(rhs, width);
+				let mut lhs_abs = lhs.to_vec();
+				if lhs_neg {
+					digits_negate(&mut lhs_abs, width);
+				}
+				let mut rhs_abs = rhs.to_vec();
+				if rhs_neg {
+					digits_negate(&mut rhs_abs, width);
+				}
+				let (_quotient, mut remainder) = digits_udivmod(&lhs_abs, &rhs_abs);
+				if lhs_neg {
+					digits_negate(&mut remainder, width);
+				}
+				lhs.copy_from_slice(&remainder);
 			}
 		}
 		self.clear_unused_bits();
@@ -511,6 +875,84 @@ mod tests {
 		}
 	}
 
+	mod increment_decrement {
+		use super::*;
+
+		#[test]
+		fn increment_by_simple() {
+			let mut x = ApInt::from(41_u32);
+			x.increment_by(1);
+			assert_eq!(x, ApInt::from(42_u32));
+		}
+
+		#[test]
+		fn increment_by_wide_carry() {
+			let mut x = ApInt::from_u128(u64::max_value() as u128);
+			x.increment_by(1);
+			assert_eq!(x, ApInt::from_u128((u64::max_value() as u128) + 1));
+		}
+
+		#[test]
+		fn decrement_by_wide_borrow() {
+			let mut x = ApInt::from_u128(1_u128 << 64);
+			x.decrement_by(1);
+			assert_eq!(x, ApInt::from_u128((1_u128 << 64) - 1));
+		}
+	}
+
+	mod overflowing_add {
+		use super::*;
+
+		#[test]
+		fn no_overflow() {
+			let lhs = ApInt::from(40_u8);
+			let rhs = ApInt::from(2_u8);
+			let (result, overflow) = lhs.into_overflowing_add(&rhs).unwrap();
+			assert_eq!(result, ApInt::from(42_u8));
+			assert!(!overflow);
+		}
+
+		#[test]
+		fn with_overflow() {
+			let lhs = ApInt::from(200_u8);
+			let rhs = ApInt::from(100_u8);
+			let (result, overflow) = lhs.into_overflowing_add(&rhs).unwrap();
+			assert_eq!(result, ApInt::from(44_u8));
+			assert!(overflow);
+		}
+
+		#[test]
+		fn wide_with_overflow() {
+			let lhs = ApInt::from_u128(u128::max_value());
+			let rhs = ApInt::from_u128(1);
+			let (result, overflow) = lhs.into_overflowing_add(&rhs).unwrap();
+			assert_eq!(result, ApInt::from_u128(0));
+			assert!(overflow);
+		}
+	}
+
+	mod overflowing_sub {
+		use super::*;
+
+		#[test]
+		fn no_overflow() {
+			let lhs = ApInt::from(42_u8);
+			let rhs = ApInt::from(2_u8);
+			let (result, overflow) = lhs.into_overflowing_sub(&rhs).unwrap();
+			assert_eq!(result, ApInt::from(40_u8));
+			assert!(!overflow);
+		}
+
+		#[test]
+		fn with_overflow() {
+			let lhs = ApInt::from(2_u8);
+			let rhs = ApInt::from(42_u8);
+			let (result, overflow) = lhs.into_overflowing_sub(&rhs).unwrap();
+			assert_eq!(result, ApInt::from(216_u8));
+			assert!(overflow);
+		}
+	}
+
 	mod mul {
 		use super::*;
 
@@ -521,6 +963,22 @@ mod tests {
 			let result = lhs.into_checked_mul(&rhs).unwrap();
 			assert_eq!(result, ApInt::from(55_u32));
 		}
+
+		#[test]
+		fn wide() {
+			let lhs = ApInt::from_u128(0x0000_0001_0000_0000_0000_0002_0000_0000);
+			let rhs = ApInt::from_u128(3);
+			let result = lhs.into_checked_mul(&rhs).unwrap();
+			assert_eq!(result, ApInt::from_u128(0x0000_0003_0000_0000_0000_0006_0000_0000));
+		}
+
+		#[test]
+		fn overflowing() {
+			let lhs = ApInt::from_u128(u128::max_value());
+			let rhs = ApInt::from_u128(2);
+			let result = lhs.into_checked_mul(&rhs).unwrap();
+			assert_eq!(result, ApInt::from_u128(u128::max_value().wrapping_mul(2)));
+		}
 	}
 
 	mod udiv {
@@ -533,6 +991,14 @@ mod tests {
 			let result = lhs.into_checked_udiv(&rhs).unwrap();
 			assert_eq!(result, ApInt::from(8_u32));
 		}
+
+		#[test]
+		fn wide() {
+			let lhs = ApInt::from_u128(0x0000_0001_0000_0000_0000_0000_0000_0000);
+			let rhs = ApInt::from_u128(0x0000_0000_0000_0001_0000_0000_0000_0000);
+			let result = lhs.into_checked_udiv(&rhs).unwrap();
+			assert_eq!(result, ApInt::from_u128(0x1_0000_0000));
+		}
 	}
 
 	mod sdiv {
@@ -553,6 +1019,14 @@ mod tests {
 			let result = lhs.into_checked_sdiv(&rhs).unwrap();
 			assert_eq!(result, ApInt::from(-6_i32));
 		}
+
+		#[test]
+		fn wide() {
+			let lhs = ApInt::from_i128(-72);
+			let rhs = ApInt::from_i128(12);
+			let result = lhs.into_checked_sdiv(&rhs).unwrap();
+			assert_eq!(result, ApInt::from_i128(-6));
+		}
 	}
 
 	mod urem {
@@ -565,6 +1039,14 @@ mod tests {
 			let result = lhs.into_checked_urem(&rhs).unwrap();
 			assert_eq!(result, ApInt::from(3_u32));
 		}
+
+		#[test]
+		fn wide() {
+			let lhs = ApInt::from_u128(0x0000_0001_0000_0000_0000_0000_0000_0003);
+			let rhs = ApInt::from_u128(0x0000_0000_0000_0001_0000_0000_0000_0000);
+			let result = lhs.into_checked_urem(&rhs).unwrap();
+			assert_eq!(result, ApInt::from_u128(3));
+		}
 	}
 
 	mod srem {
@@ -585,6 +1067,14 @@ mod tests {
 			let result = lhs.into_checked_srem(&rhs).unwrap();
 			assert_eq!(result, ApInt::from(-2_i32));
 		}
+
+		#[test]
+		fn wide() {
+			let lhs = ApInt::from_i128(-23);
+			let rhs = ApInt::from_i128(7);
+			let result = lhs.into_checked_srem(&rhs).unwrap();
+			assert_eq!(result, ApInt::from_i128(-2));
+		}
 	}
 
 }
@@ -0,0 +1,251 @@
+use apint::{ApInt};
+use digit;
+use digit::{Digit, Bit};
+
+use core::marker::PhantomData;
+
+/// Selects whether a `bits()`/`bits_mut()` iterator walks from the most
+/// significant bit down to the least significant (`Msb0`), or from the
+/// least significant bit up to the most significant (`Lsb0`).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum BitOrder {
+	Msb0,
+	Lsb0,
+}
+
+impl BitOrder {
+	/// Maps a front-to-back iteration index (counted from `0`, the bit
+	/// yielded first) to the absolute bit position within an `ApInt` of
+	/// `width` bits.
+	#[inline]
+	fn position(self, width: usize, index: usize) -> usize {
+		match self {
+			BitOrder::Lsb0 => index,
+			BitOrder::Msb0 => width - 1 - index,
+		}
+	}
+}
+
+/// An iterator over the individual bits of an `ApInt`'s value, in the order
+/// selected by a `BitOrder`.
+///
+/// Created by `ApInt::bits`.
+#[derive(Debug, Clone)]
+pub struct Bits<'a> {
+	digits: &'a [Digit],
+	width: usize,
+	order: BitOrder,
+	front: usize,
+	back: usize,
+}
+
+impl<'a> Bits<'a> {
+	pub(crate) fn new(digits: &'a [Digit], width: usize, order: BitOrder) -> Bits<'a> {
+		Bits { digits, width, order, front: 0, back: width }
+	}
+
+	fn get(&self, index: usize) -> Bit {
+		let pos = self.order.position(self.width, index);
+		self.digits[pos / digit::BITS].get(pos % digit::BITS).unwrap()
+	}
+}
+
+impl<'a> Iterator for Bits<'a> {
+	type Item = Bit;
+
+	fn next(&mut self) -> Option<Bit> {
+		if self.front == self.back {
+			return None
+		}
+		let bit = self.get(self.front);
+		self.front += 1;
+		Some(bit)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let remaining = self.back - self.front;
+		(remaining, Some(remaining))
+	}
+}
+
+impl<'a> DoubleEndedIterator for Bits<'a> {
+	fn next_back(&mut self) -> Option<Bit> {
+		if self.front == self.back {
+			return None
+		}
+		self.back -= 1;
+		Some(self.get(self.back))
+	}
+}
+
+impl<'a> ExactSizeIterator for Bits<'a> {
+	fn len(&self) -> usize {
+		self.back - self.front
+	}
+}
+
+/// A mutable handle onto a single bit of an `ApInt`, yielded by `BitsMut`.
+///
+/// # Safety
+///
+/// Stores a raw pointer into the `ApInt`'s digit storage rather than a
+/// borrowed `&mut Digit`, since several `BitMut`s handed out by the same
+/// `BitsMut` may point into the same underlying `Digit` (several bits live
+/// in one digit). Each `BitMut` only ever reads or writes its own single
+/// bit, and `BitsMut` holds the `ApInt` borrowed mutably for as long as any
+/// `BitMut` it produced can be alive, so this can never race with another
+/// access to the same storage.
+pub struct BitMut<'a> {
+	digit: *mut Digit,
+	bit: usize,
+	marker: PhantomData<&'a mut Digit>,
+}
+
+impl<'a> BitMut<'a> {
+	/// Returns the current state of this bit.
+	pub fn get(&self) -> Bit {
+		unsafe { (*self.digit).get(self.bit).unwrap() }
+	}
+
+	/// Sets this bit to `1`.
+	pub fn set(&mut self) {
+		unsafe { (*self.digit).set(self.bit).unwrap() }
+	}
+
+	/// Sets this bit to `0`.
+	pub fn unset(&mut self) {
+		unsafe { (*self.digit).unset(self.bit).unwrap() }
+	}
+
+	/// Flips this bit.
+	pub fn flip(&mut self) {
+		unsafe { (*self.digit).flip(self.bit).unwrap() }
+	}
+}
+
+/// An iterator over mutable handles to the individual bits of an `ApInt`'s
+/// value, in the order selected by a `BitOrder`.
+///
+/// Created by `ApInt::bits_mut`.
+pub struct BitsMut<'a> {
+	digits: *mut Digit,
+	width: usize,
+	order: BitOrder,
+	front: usize,
+	back: usize,
+	marker: PhantomData<&'a mut [Digit]>,
+}
+
+impl<'a> BitsMut<'a> {
+	pub(crate) fn new(digits: &'a mut [Digit], width: usize, order: BitOrder) -> BitsMut<'a> {
+		BitsMut {
+			digits: digits.as_mut_ptr(),
+			width,
+			order,
+			front: 0,
+			back: width,
+			marker: PhantomData,
+		}
+	}
+
+	fn bit_mut(&mut self, index: usize) -> BitMut<'a> {
+		let pos = self.order.position(self.width, index);
+		let digit = unsafe { self.digits.add(pos / digit::BITS) };
+		BitMut { digit, bit: pos % digit::BITS, marker: PhantomData }
+	}
+}
+
+impl<'a> Iterator for BitsMut<'a> {
+	type Item = BitMut<'a>;
+
+	fn next(&mut self) -> Option<BitMut<'a>> {
+		if self.front == self.back {
+			return None
+		}
+		let bit = self.bit_mut(self.front);
+		self.front += 1;
+		Some(bit)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let remaining = self.back - self.front;
+		(remaining, Some(remaining))
+	}
+}
+
+impl<'a> DoubleEndedIterator for BitsMut<'a> {
+	fn next_back(&mut self) -> Option<BitMut<'a>> {
+		if self.front == self.back {
+			return None
+		}
+		self.back -= 1;
+		Some(self.bit_mut(self.back))
+	}
+}
+
+impl<'a> ExactSizeIterator for BitsMut<'a> {
+	fn len(&self) -> usize {
+		self.back - self.front
+	}
+}
+
+/// # Bit Iteration
+impl ApInt {
+	/// Returns an iterator over the individual bits of `self`'s value,
+	/// ordered according to `order`.
+	pub fn bits(&self, order: BitOrder) -> Bits {
+		Bits::new(self.as_digit_slice(), self.len_bits(), order)
+	}
+
+	/// Returns an iterator over mutable handles to the individual bits of
+	/// `self`'s value, ordered according to `order`.
+	pub fn bits_mut(&mut self, order: BitOrder) -> BitsMut {
+		let width = self.len_bits();
+		BitsMut::new(self.as_digit_slice_mut(), width, order)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use bitwidth::{BitWidth};
+
+	#[test]
+	fn lsb0_matches_increasing_bit_positions() {
+		let apint = ApInt::from(0b1010_u32);
+		let bits: Vec<bool> = apint.bits(BitOrder::Lsb0).take(4).map(|b| b.into()).collect();
+		assert_eq!(bits, vec![false, true, false, true]);
+	}
+
+	#[test]
+	fn msb0_matches_decreasing_bit_positions() {
+		let apint = ApInt::from(0b1010_u32);
+		let bits: Vec<bool> = apint.bits(BitOrder::Msb0).map(|b| b.into()).collect();
+		assert_eq!(bits[28..], [true, false, true, false][..]);
+	}
+
+	#[test]
+	fn len_matches_bit_width() {
+		let apint = ApInt::zero(BitWidth::new(37).unwrap());
+		assert_eq!(apint.bits(BitOrder::Lsb0).len(), 37);
+	}
+
+	#[test]
+	fn double_ended_consumes_from_both_sides() {
+		let apint = ApInt::from(0b1100_u32);
+		let mut it = apint.bits(BitOrder::Lsb0);
+		assert_eq!(bool::from(it.next().unwrap()), false);
+		assert_eq!(bool::from(it.next_back().unwrap()), false);
+		assert_eq!(it.len(), 30);
+	}
+
+	#[test]
+	fn bits_mut_sets_and_unsets_bits() {
+		let mut apint = ApInt::zero(BitWidth::w32());
+		apint.bits_mut(BitOrder::Lsb0).nth(3).unwrap().set();
+		assert_eq!(apint, ApInt::from(0b1000_u32));
+
+		apint.bits_mut(BitOrder::Lsb0).nth(3).unwrap().unset();
+		assert_eq!(apint, ApInt::zero(BitWidth::w32()));
+	}
+}
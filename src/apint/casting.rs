@@ -0,0 +1,342 @@
+use apint::{ApInt};
+use apint::bits::{BitOrder};
+use bitwidth::{BitWidth};
+use digit;
+use digit::{Digit, DigitRepr};
+
+// ============================================================================
+//  A note on `from_f64`/`from_f32` and the `Wrap` policy
+// ----------------------------------------------------------------------------
+//
+//  `ApInt` has no generic "resize to width" primitive in this crate (widths
+//  are fixed at construction time), so a float whose integer part needs more
+//  bits than `target_width` cannot simply be built at full precision and then
+//  truncated down. For `FloatConversionPolicy::Wrap` this module instead
+//  places each mantissa bit directly at its target position (position
+//  `mantissa_bit + shift`), bit by bit, via `ApInt::bits_mut`, and drops the
+//  bits whose position is at or above `target_width`. This keeps the whole computation in exact
+//  integer arithmetic instead of round-tripping through a float, which would
+//  lose precision (and in fact wrap incorrectly) once `target_width` grows
+//  past what the float's own mantissa can represent exactly.
+// ============================================================================
+
+/// Policy for `from_f64`/`from_f32` conversions whose magnitude does not fit
+/// the requested target `BitWidth`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum FloatConversionPolicy {
+	/// Clamp the result to `ApInt::all_set(target_width)`.
+	Saturate,
+	/// Keep only the low `target_width` bits of the magnitude.
+	Wrap,
+}
+
+/// Returns the bit position of the most significant set bit in `digits`,
+/// i.e. `floor(log2(value))`. Panics if every digit is zero; callers must
+/// check `is_zero` first.
+fn most_significant_set_bit(digits: &[Digit]) -> usize {
+	let top_idx = digits.iter().rposition(|d| d.repr() != 0)
+		.expect("most_significant_set_bit called on an all-zero digit slice");
+	let top = digits[top_idx].repr();
+	top_idx * digit::BITS + (digit::BITS - top.leading_zeros() as usize - 1)
+}
+
+/// Returns `true` if the bit at position `n` is set within the little-endian
+/// `digits`, or `false` if `n` is out of range.
+fn digit_bit(digits: &[Digit], n: usize) -> bool {
+	let idx = n / digit::BITS;
+	if idx >= digits.len() {
+		return false
+	}
+	(digits[idx].repr() >> (n % digit::BITS)) & 0x1 == 0x1
+}
+
+/// Rounds the magnitude described by `digits` (whose most significant set bit
+/// is at position `msb`) to `mantissa_bits` significant bits, round-half-to-
+/// even, and returns `(mantissa, exponent)` such that the rounded value
+/// equals `mantissa * 2^exponent`.
+fn round_to_mantissa(digits: &[Digit], msb: usize, mantissa_bits: usize) -> (u64, i64) {
+	if msb < mantissa_bits {
+		let mut mantissa: u64 = 0;
+		for pos in 0 ..= msb {
+			if digit_bit(digits, pos) {
+				mantissa |= 1 << pos;
+			}
+		}
+		return (mantissa, 0)
+	}
+
+	let mut shift = msb - (mantissa_bits - 1);
+	let mut mantissa: u64 = 0;
+	for i in 0 .. mantissa_bits {
+		if digit_bit(digits, shift + i) {
+			mantissa |= 1 << i;
+		}
+	}
+	let round_bit = digit_bit(digits, shift - 1);
+	let sticky = (0 .. shift - 1).any(|pos| digit_bit(digits, pos));
+	if round_bit && (sticky || (mantissa & 0x1 == 0x1)) {
+		mantissa += 1;
+		if mantissa == (1 << mantissa_bits) {
+			mantissa >>= 1;
+			shift += 1;
+		}
+	}
+	(mantissa, shift as i64)
+}
+
+/// Decomposes `(mantissa, exponent)` pair describing a nonnegative float back
+/// into an `ApInt` of `target_width` bits, handling `nan`/`infinite`/negative
+/// inputs and out-of-range magnitudes per `policy`. `exponent` is the power
+/// of two of `mantissa`'s most significant bit, i.e. the represented value is
+/// `mantissa * 2^(exponent - (mantissa_bits - 1))`.
+fn from_decomposed(
+	is_negative: bool,
+	is_nan: bool,
+	is_infinite: bool,
+	mantissa: u64,
+	exponent: i64,
+	mantissa_bits: usize,
+	target_width: BitWidth,
+	policy: FloatConversionPolicy,
+) -> ApInt {
+	if is_nan || is_negative || mantissa == 0 {
+		return ApInt::zero(target_width)
+	}
+	if is_infinite {
+		return match policy {
+			FloatConversionPolicy::Saturate => ApInt::all_set(target_width),
+			FloatConversionPolicy::Wrap => ApInt::zero(target_width),
+		}
+	}
+
+	let shift = exponent - (mantissa_bits as i64 - 1);
+	if shift < 0 {
+		// The value has a fractional part (or is a subnormal smaller than
+		// `1.0`); truncate it toward zero, same as a plain `as` float-to-int
+		// cast would. A shift amount of `64` or more means nothing of the
+		// integer part survives.
+		let whole = if -shift >= 64 { 0 } else { mantissa >> (-shift) as u32 };
+		// `increment_by` below wraps, which is already what `Wrap` keeps.
+		if policy == FloatConversionPolicy::Saturate
+			&& exponent >= 0
+			&& exponent as usize + 1 > target_width.to_usize()
+		{
+			return ApInt::all_set(target_width)
+		}
+		let mut result = ApInt::zero(target_width);
+		result.increment_by(whole);
+		return result
+	}
+
+	let required_bits = exponent as usize + 1;
+	if required_bits > target_width.to_usize() {
+		return match policy {
+			FloatConversionPolicy::Saturate => ApInt::all_set(target_width),
+			FloatConversionPolicy::Wrap => {
+				// `Wrap` keeps the low `target_width` bits of the unwrapped
+				// value `mantissa << shift`, i.e. bits `0 .. target_width` of
+				// it, and drops the rest. `mantissa` occupies bits `shift ..
+				// shift + mantissa_bits` of that unwrapped value, so copy
+				// only the ones of those that fall below `target_width`
+				// straight across, bit by bit, instead of going through a
+				// float (which cannot represent `target_width` bits exactly
+				// once it grows past the float's own mantissa width).
+				let mut result = ApInt::zero(target_width);
+				let width = target_width.to_usize();
+				if (shift as usize) < width {
+					for i in 0 .. mantissa_bits {
+						let pos = shift as usize + i;
+						if pos >= width {
+							break
+						}
+						if (mantissa >> i) & 0x1 == 0x1 {
+							result.bits_mut(BitOrder::Lsb0).nth(pos).unwrap().set();
+						}
+					}
+				}
+				result
+			}
+		}
+	}
+
+	let mut result = ApInt::zero(target_width);
+	result.increment_by(mantissa);
+	result.checked_shl_assign(shift as usize).unwrap();
+	result
+}
+
+/// # Float Conversions
+impl ApInt {
+	/// Returns `self`'s value converted to the nearest `f64`, rounding
+	/// half-to-even, with `±inf` returned if the magnitude exceeds `f64`'s
+	/// range.
+	pub fn to_f64(&self) -> f64 {
+		if self.is_zero() {
+			return 0.0
+		}
+		let digits = self.as_digit_slice();
+		let msb = most_significant_set_bit(digits);
+		let (mantissa, exponent) = round_to_mantissa(digits, msb, 53);
+		(mantissa as f64) * 2f64.powi(exponent as i32)
+	}
+
+	/// Returns `self`'s value converted to the nearest `f32`, rounding
+	/// half-to-even, with `±inf` returned if the magnitude exceeds `f32`'s
+	/// range.
+	pub fn to_f32(&self) -> f32 {
+		if self.is_zero() {
+			return 0.0
+		}
+		let digits = self.as_digit_slice();
+		let msb = most_significant_set_bit(digits);
+		let (mantissa, exponent) = round_to_mantissa(digits, msb, 24);
+		(mantissa as f32) * 2f32.powi(exponent as i32)
+	}
+
+	/// Converts `value` into an `ApInt` of `target_width` bits, truncating
+	/// any fractional part toward zero.
+	///
+	/// `NaN` and negative values convert to zero. Magnitudes that do not fit
+	/// `target_width` are handled according to `policy` (see
+	/// `FloatConversionPolicy`).
+	pub fn from_f64(value: f64, target_width: BitWidth, policy: FloatConversionPolicy) -> ApInt {
+		let bits = value.to_bits();
+		let exponent_biased = ((bits >> 52) & 0x7FF) as i64;
+		let mantissa_frac = bits & 0x000F_FFFF_FFFF_FFFF;
+		let is_subnormal = exponent_biased == 0;
+		let mantissa = if is_subnormal { mantissa_frac } else { mantissa_frac | (1 << 52) };
+		let exponent = if is_subnormal { -1022 } else { exponent_biased - 1023 };
+		from_decomposed(
+			value.is_sign_negative() && value != 0.0,
+			value.is_nan(),
+			value.is_infinite(),
+			mantissa,
+			exponent,
+			53,
+			target_width,
+			policy,
+		)
+	}
+
+	/// Converts `value` into an `ApInt` of `target_width` bits, truncating
+	/// any fractional part toward zero. See `from_f64` for the handling of
+	/// `NaN`, negative values, and out-of-range magnitudes.
+	pub fn from_f32(value: f32, target_width: BitWidth, policy: FloatConversionPolicy) -> ApInt {
+		let bits = value.to_bits();
+		let exponent_biased = ((bits >> 23) & 0xFF) as i64;
+		let mantissa_frac = DigitRepr::from(bits & 0x007F_FFFF);
+		let is_subnormal = exponent_biased == 0;
+		let mantissa = if is_subnormal { mantissa_frac } else { mantissa_frac | (1 << 23) };
+		let exponent = if is_subnormal { -126 } else { exponent_biased - 127 };
+		from_decomposed(
+			value.is_sign_negative() && value != 0.0,
+			value.is_nan(),
+			value.is_infinite(),
+			mantissa,
+			exponent,
+			24,
+			target_width,
+			policy,
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	mod to_float {
+		use super::*;
+
+		#[test]
+		fn exact_small_values_round_trip() {
+			for &v in &[0u64, 1, 2, 42, 1337, 1 << 52] {
+				let apint = ApInt::from_u64(v);
+				assert_eq!(apint.to_f64(), v as f64);
+				assert_eq!(apint.to_f32(), v as f32);
+			}
+		}
+
+		#[test]
+		fn zero_is_zero() {
+			assert_eq!(ApInt::zero(BitWidth::w64()).to_f64(), 0.0);
+			assert_eq!(ApInt::zero(BitWidth::w64()).to_f32(), 0.0);
+		}
+
+		#[test]
+		fn rounds_to_nearest_even_on_overflowing_mantissa() {
+			// `u64::max_value()` needs 64 significant bits; as `f64` (53-bit
+			// mantissa) it must round up to `2^64`.
+			let apint = ApInt::from_u64(u64::max_value());
+			assert_eq!(apint.to_f64(), 2f64.powi(64));
+		}
+
+		#[test]
+		fn matches_known_wide_value() {
+			let width = BitWidth::new(128).unwrap();
+			let mut apint = ApInt::zero(width);
+			apint.increment_by(1);
+			apint.checked_shl_assign(100).unwrap();
+			assert_eq!(apint.to_f64(), 2f64.powi(100));
+		}
+	}
+
+	mod from_float {
+		use super::*;
+
+		#[test]
+		fn truncates_fractional_part() {
+			let width = BitWidth::new(32).unwrap();
+			let apint = ApInt::from_f64(42.75, width, FloatConversionPolicy::Saturate);
+			let mut expected = ApInt::zero(width);
+			expected.increment_by(42);
+			assert_eq!(apint, expected);
+		}
+
+		#[test]
+		fn negative_and_nan_convert_to_zero() {
+			let width = BitWidth::new(32).unwrap();
+			assert_eq!(
+				ApInt::from_f64(-1.0, width, FloatConversionPolicy::Saturate),
+				ApInt::zero(width)
+			);
+			assert_eq!(
+				ApInt::from_f64(::std::f64::NAN, width, FloatConversionPolicy::Saturate),
+				ApInt::zero(width)
+			);
+		}
+
+		#[test]
+		fn saturates_on_overflow() {
+			let width = BitWidth::new(8).unwrap();
+			assert_eq!(
+				ApInt::from_f64(1000.0, width, FloatConversionPolicy::Saturate),
+				ApInt::all_set(width)
+			);
+		}
+
+		#[test]
+		fn wraps_high_bits_for_width_above_64() {
+			// `2^69 + 2^68` needs 70 significant bits, one more than
+			// `target_width` below, so `Wrap` must drop bit 69 and keep
+			// only bit 68 -- exercised at a width above 64 bits, where the
+			// wrapped value no longer fits in a single `u64`.
+			let width = BitWidth::new(69).unwrap();
+			let value = 2f64.powi(69) + 2f64.powi(68);
+			let result = ApInt::from_f64(value, width, FloatConversionPolicy::Wrap);
+			let mut expected = ApInt::zero(width);
+			expected.increment_by(1);
+			expected.checked_shl_assign(68).unwrap();
+			assert_eq!(result, expected);
+		}
+
+		#[test]
+		fn round_trips_through_to_f64() {
+			let width = BitWidth::new(64).unwrap();
+			let mut apint = ApInt::zero(width);
+			apint.increment_by(1_000_000);
+			let back = ApInt::from_f64(apint.to_f64(), width, FloatConversionPolicy::Saturate);
+			assert_eq!(apint, back);
+		}
+	}
+}
@@ -0,0 +1,279 @@
+use apint::{ApInt};
+use errors::{DivOp, Error, Result};
+use digit;
+use digit::{Digit, DigitRepr};
+use ll;
+
+use core::cmp::Ordering;
+
+/// Returns `true` if the bit at position `n` is set within the little-endian `digits`.
+fn digits_get_bit(digits: &[Digit], n: usize) -> bool {
+	(digits[n / digit::BITS].repr() >> (n % digit::BITS)) & 0x1 == 0x1
+}
+
+/// Sets the bit at position `n` within `digits` to the boolean state encoded by `mask`
+/// (`0` for unset, all-ones for set), without branching on the resulting value.
+fn digits_set_bit_masked(digits: &mut [Digit], n: usize, mask: DigitRepr) {
+	let idx = n / digit::BITS;
+	let bit = n % digit::BITS;
+	let cleared = digits[idx].repr() & !(1 << bit);
+	digits[idx] = Digit(cleared | (mask & (1 << bit)));
+}
+
+/// Shifts `digits` left by one bit inplace, losing the top-most bit.
+fn digits_shl_one(digits: &mut [Digit]) {
+	let mut carry: DigitRepr = 0;
+	for d in digits.iter_mut() {
+		let repr = d.repr();
+		let next_carry = repr >> (digit::BITS - 1);
+		*d = Digit((repr << 1) | carry);
+		carry = next_carry;
+	}
+}
+
+/// Returns `(gt, eq)` masks (each either all-ones or all-zero) for whether
+/// `lhs > rhs` and `lhs == rhs` respectively, folding the per-digit
+/// comparison over every digit instead of returning as soon as a difference
+/// is found. `gt` and `eq` are never both all-ones at once.
+fn digits_cmp_masks(lhs: &[Digit], rhs: &[Digit]) -> (DigitRepr, DigitRepr) {
+	let mut gt: DigitRepr = 0;
+	let mut eq: DigitRepr = !0;
+	for i in (0 .. lhs.len()).rev() {
+		let (l, r) = (lhs[i].repr(), rhs[i].repr());
+		let digit_gt = ((l > r) as DigitRepr).wrapping_neg();
+		let digit_eq = ((l == r) as DigitRepr).wrapping_neg();
+		gt |= eq & digit_gt;
+		eq &= digit_eq;
+	}
+	(gt, eq)
+}
+
+/// Returns an all-ones mask if `lhs >= rhs` and an all-zero mask otherwise,
+/// folding over every digit instead of returning as soon as a difference is found.
+fn digits_uge_mask(lhs: &[Digit], rhs: &[Digit]) -> DigitRepr {
+	let (gt, eq) = digits_cmp_masks(lhs, rhs);
+	gt | eq
+}
+
+/// Subtracts `rhs` from `lhs` inplace if `mask` is all-ones, or leaves `lhs`
+/// untouched if `mask` is zero. Every digit is always touched; only the
+/// effective value being subtracted (`rhs` or all-zero) is selected by `mask`.
+fn digits_sub_assign_masked(lhs: &mut [Digit], rhs: &[Digit], mask: DigitRepr) {
+	let mut borrow = Digit::zero();
+	for (l, r) in lhs.iter_mut().zip(rhs.iter()) {
+		let masked = Digit(r.repr() & mask);
+		*l = ll::borrow_sub(*l, masked, &mut borrow);
+	}
+}
+
+/// Fixed-iteration restoring division (one iteration per bit of the digit
+/// buffers, `dividend.len() * digit::BITS` in all) that never branches on the
+/// value of `dividend` or `divisor`: every iteration always shifts, always
+/// computes a comparison mask and always performs a masked subtract, as
+/// opposed to the early-exit, data-dependent `if` of `digits_udivmod` in
+/// `arithmetic.rs`. Suitable as a building block for modular arithmetic over
+/// secret values.
+fn digits_ct_udivmod(dividend: &[Digit], divisor: &[Digit]) -> (Vec<Digit>, Vec<Digit>) {
+	let len = dividend.len();
+	let mut quotient = vec![Digit::zero(); len];
+	let mut remainder = vec![Digit::zero(); len];
+	for i in (0 .. len * digit::BITS).rev() {
+		digits_shl_one(&mut remainder);
+		let bit = digits_get_bit(dividend, i) as DigitRepr;
+		remainder[0] = Digit(remainder[0].repr() | bit);
+		let mask = digits_uge_mask(&remainder, divisor);
+		digits_sub_assign_masked(&mut remainder, divisor, mask);
+		digits_set_bit_masked(&mut quotient, i, mask);
+	}
+	(quotient, remainder)
+}
+
+/// # Constant-Time Arithmetic
+///
+/// These operations never branch and never exit a loop early based on the
+/// value of their operands, unlike their `checked_*` counterparts. They are
+/// intended as building blocks for implementing modular arithmetic over
+/// secret values (e.g. cryptographic big-integer code) on top of `ApInt`
+/// without reintroducing the value-dependent control flow of the rest of
+/// this crate.
+impl ApInt {
+
+	/// Returns `true` if `self` and `rhs` represent the same value, without
+	/// branching or exiting early on the first digit that differs.
+	///
+	/// # Errors
+	///
+	/// - If `self` and `rhs` have unmatching bit widths.
+	pub fn ct_eq(&self, rhs: &ApInt) -> Result<bool> {
+		if self.len_bits() != rhs.len_bits() {
+			return Error::unmatching_bitwidths(self.len_bits(), rhs.len_bits()).into()
+		}
+		let mut diff = Digit::zero();
+		for (l, r) in self.as_digit_slice().iter().zip(rhs.as_digit_slice().iter()) {
+			diff = Digit(diff.repr() | (l.repr() ^ r.repr()));
+		}
+		Ok(diff.repr() == 0)
+	}
+
+	/// Returns `true` if `self` is **unsigned** less-than `rhs`, folding the
+	/// per-digit comparison across all digits instead of short-circuiting on
+	/// the first differing digit.
+	///
+	/// # Errors
+	///
+	/// - If `self` and `rhs` have unmatching bit widths.
+	pub fn ct_ult(&self, rhs: &ApInt) -> Result<bool> {
+		if self.len_bits() != rhs.len_bits() {
+			return Error::unmatching_bitwidths(self.len_bits(), rhs.len_bits()).into()
+		}
+		// `self < rhs` iff `rhs > self`; both masks are computed unconditionally
+		// by `digits_cmp_masks` and combined as words, only turning into a
+		// `bool` once, here, at the very end.
+		let (gt, _eq) = digits_cmp_masks(rhs.as_digit_slice(), self.as_digit_slice());
+		Ok(gt != 0)
+	}
+
+	/// Returns the **unsigned** ordering of `self` relative to `rhs`, folding
+	/// the per-digit comparison across all digits instead of short-circuiting
+	/// on the first differing digit.
+	///
+	/// # Errors
+	///
+	/// - If `self` and `rhs` have unmatching bit widths.
+	pub fn ct_cmp(&self, rhs: &ApInt) -> Result<Ordering> {
+		if self.len_bits() != rhs.len_bits() {
+			return Error::unmatching_bitwidths(self.len_bits(), rhs.len_bits()).into()
+		}
+		let (gt, eq) = digits_cmp_masks(self.as_digit_slice(), rhs.as_digit_slice());
+		Ok(if eq != 0 {
+			Ordering::Equal
+		} else if gt != 0 {
+			Ordering::Greater
+		} else {
+			Ordering::Less
+		})
+	}
+
+	/// Add-assigns `rhs` to `self` inplace.
+	///
+	/// The ripple-carry loop backing `checked_add_assign` already touches
+	/// every digit unconditionally, so this is simply the constant-time
+	/// entry point into the same implementation.
+	///
+	/// # Errors
+	///
+	/// - If `self` and `rhs` have unmatching bit widths.
+	pub fn ct_add_assign(&mut self, rhs: &ApInt) -> Result<()> {
+		self.checked_add_assign(rhs)
+	}
+
+	/// Subtract-assigns `rhs` from `self` inplace.
+	///
+	/// The ripple-borrow loop backing `checked_sub_assign` already touches
+	/// every digit unconditionally, so this is simply the constant-time
+	/// entry point into the same implementation.
+	///
+	/// # Errors
+	///
+	/// - If `self` and `rhs` have unmatching bit widths.
+	pub fn ct_sub_assign(&mut self, rhs: &ApInt) -> Result<()> {
+		self.checked_sub_assign(rhs)
+	}
+
+	/// Divides `self` by `rhs` using **unsigned** interpretation, without
+	/// branching or exiting early based on the value of either operand, once
+	/// past the initial validity checks below (the zero-divisor and
+	/// bit-width checks are argument *validation*, not part of the numeric
+	/// computation, and are intentionally allowed to branch and return early
+	/// like every other fallible `ApInt` operation in this crate).
+	///
+	/// # Errors
+	///
+	/// - If `rhs` is zero.
+	/// - If `self` and `rhs` have unmatching bit widths.
+	pub fn ct_udiv(&self, rhs: &ApInt) -> Result<ApInt> {
+		if rhs.is_zero() {
+			return Err(Error::division_by_zero(DivOp::UnsignedDiv, self.clone()))
+		}
+		if self.len_bits() != rhs.len_bits() {
+			return Error::unmatching_bitwidths(self.len_bits(), rhs.len_bits()).into()
+		}
+		let (quotient, _remainder) = digits_ct_udivmod(self.as_digit_slice(), rhs.as_digit_slice());
+		let mut result = self.clone();
+		result.as_digit_slice_mut().copy_from_slice(&quotient);
+		result.clear_unused_bits();
+		Ok(result)
+	}
+
+	/// Calculates the **unsigned** remainder of `self` by `rhs`, without
+	/// branching or exiting early based on the value of either operand, once
+	/// past the initial validity checks below (see the equivalent note on
+	/// `ct_udiv`).
+	///
+	/// # Errors
+	///
+	/// - If `rhs` is zero.
+	/// - If `self` and `rhs` have unmatching bit widths.
+	pub fn ct_urem(&self, rhs: &ApInt) -> Result<ApInt> {
+		if rhs.is_zero() {
+			return Err(Error::division_by_zero(DivOp::UnsignedRem, self.clone()))
+		}
+		if self.len_bits() != rhs.len_bits() {
+			return Error::unmatching_bitwidths(self.len_bits(), rhs.len_bits()).into()
+		}
+		let (_quotient, remainder) = digits_ct_udivmod(self.as_digit_slice(), rhs.as_digit_slice());
+		let mut result = self.clone();
+		result.as_digit_slice_mut().copy_from_slice(&remainder);
+		result.clear_unused_bits();
+		Ok(result)
+	}
+
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn ct_eq_matches_checked_eq() {
+		let a = ApInt::from_u128(0x0123_4567_89AB_CDEF_0011_2233_4455_6677);
+		let b = a.clone();
+		let c = ApInt::from_u128(0x0000_0000_0000_0000_0000_0000_0000_0001);
+		assert!(a.ct_eq(&b).unwrap());
+		assert!(!a.ct_eq(&c).unwrap());
+	}
+
+	#[test]
+	fn ct_ult_matches_expected_order() {
+		let small = ApInt::from_u128(41);
+		let big = ApInt::from_u128(42);
+		assert!(small.ct_ult(&big).unwrap());
+		assert!(!big.ct_ult(&small).unwrap());
+		assert!(!big.ct_ult(&big).unwrap());
+	}
+
+	#[test]
+	fn ct_cmp_matches_expected_order() {
+		let small = ApInt::from_u128(41);
+		let big = ApInt::from_u128(42);
+		assert_eq!(small.ct_cmp(&big).unwrap(), Ordering::Less);
+		assert_eq!(big.ct_cmp(&small).unwrap(), Ordering::Greater);
+		assert_eq!(big.ct_cmp(&big).unwrap(), Ordering::Equal);
+	}
+
+	#[test]
+	fn ct_udiv_matches_checked_udiv() {
+		let lhs = ApInt::from_u128(0x0000_0001_0000_0000_0000_0000_0000_0000);
+		let rhs = ApInt::from_u128(0x0000_0000_0000_0001_0000_0000_0000_0000);
+		let result = lhs.ct_udiv(&rhs).unwrap();
+		assert_eq!(result, ApInt::from_u128(0x1_0000_0000));
+	}
+
+	#[test]
+	fn ct_urem_matches_checked_urem() {
+		let lhs = ApInt::from_u128(0x0000_0001_0000_0000_0000_0000_0000_0003);
+		let rhs = ApInt::from_u128(0x0000_0000_0000_0001_0000_0000_0000_0000);
+		let result = lhs.ct_urem(&rhs).unwrap();
+		assert_eq!(result, ApInt::from_u128(3));
+	}
+}
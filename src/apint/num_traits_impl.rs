@@ -0,0 +1,167 @@
+#[cfg(feature = "num-traits")]
+use apint::{ApInt};
+#[cfg(feature = "num-traits")]
+use bitwidth::{BitWidth};
+#[cfg(feature = "num-traits")]
+use traits::{Width};
+
+#[cfg(feature = "num-traits")]
+use num_traits::{Zero, One, Bounded, CheckedAdd, CheckedSub, CheckedMul, CheckedDiv, CheckedRem, CheckedNeg};
+
+// ============================================================================
+//  A note on width-parameterless construction
+// ----------------------------------------------------------------------------
+//
+//  `ApInt` has no notion of a "default" bit width: every value carries its
+//  own width and operations between mismatched widths are rejected. The
+//  `num-traits` traits `Zero`, `One` and `Bounded` are however defined with
+//  parameterless constructors (`fn zero() -> Self`, etc.), which cannot
+//  express a width. The implementations below pick `DEFAULT_WIDTH` (the
+//  native digit width) as a practical default, matching the convention
+//  already used elsewhere in this crate (e.g. `ApInt::from_str_radix`'s
+//  zero-width examples default to 64 bits). Callers that need a `Zero`/`One`/
+//  `Bounded` value of a specific width should keep using the width-taking
+//  inherent constructors (`ApInt::zero`, `ApInt::one`, `ApInt::all_set`,
+//  `ApInt::signed_min_value`, `ApInt::signed_max_value`) instead of going
+//  through `num-traits`.
+//
+//  For the same reason this module does not implement `num_traits::Signed`
+//  or `num_traits::Unsigned` for `ApInt`: those are marker/behavioral traits
+//  that fix a single interpretation of the bit pattern, whereas every
+//  arithmetic operation in this crate already requires the caller to pick
+//  signed or unsigned explicitly (`checked_sdiv_assign` vs
+//  `checked_udiv_assign`, etc). A blanket `Signed` impl would silently commit
+//  `ApInt` to the signed interpretation everywhere, which would be
+//  misleading.
+// ============================================================================
+
+#[cfg(feature = "num-traits")]
+const DEFAULT_WIDTH: usize = 64;
+
+#[cfg(feature = "num-traits")]
+fn default_width() -> BitWidth {
+	BitWidth::new(DEFAULT_WIDTH).unwrap()
+}
+
+#[cfg(feature = "num-traits")]
+impl Zero for ApInt {
+	fn zero() -> ApInt {
+		ApInt::zero(default_width())
+	}
+
+	fn is_zero(&self) -> bool {
+		ApInt::is_zero(self)
+	}
+}
+
+#[cfg(feature = "num-traits")]
+impl One for ApInt {
+	fn one() -> ApInt {
+		ApInt::one(default_width())
+	}
+}
+
+/// Bounds of an `ApInt` of `DEFAULT_WIDTH` bits under the **unsigned**
+/// interpretation (all-zeros / all-ones). See the module-level note on why
+/// `num_traits::Bounded` cannot express an arbitrary width; use
+/// `ApInt::signed_min_value`/`ApInt::signed_max_value` directly for the
+/// signed interpretation at a specific width.
+#[cfg(feature = "num-traits")]
+impl Bounded for ApInt {
+	fn min_value() -> ApInt {
+		ApInt::zero(default_width())
+	}
+
+	fn max_value() -> ApInt {
+		ApInt::all_set(default_width())
+	}
+}
+
+#[cfg(feature = "num-traits")]
+impl CheckedAdd for ApInt {
+	fn checked_add(&self, v: &ApInt) -> Option<ApInt> {
+		self.clone().into_checked_add(v).ok()
+	}
+}
+
+#[cfg(feature = "num-traits")]
+impl CheckedSub for ApInt {
+	fn checked_sub(&self, v: &ApInt) -> Option<ApInt> {
+		self.clone().into_checked_sub(v).ok()
+	}
+}
+
+#[cfg(feature = "num-traits")]
+impl CheckedMul for ApInt {
+	fn checked_mul(&self, v: &ApInt) -> Option<ApInt> {
+		self.clone().into_checked_mul(v).ok()
+	}
+}
+
+/// Maps to the **unsigned** division, since `num_traits::CheckedDiv` carries
+/// no signedness of its own and this crate otherwise always requires callers
+/// to pick `checked_udiv_assign` or `checked_sdiv_assign` explicitly.
+#[cfg(feature = "num-traits")]
+impl CheckedDiv for ApInt {
+	fn checked_div(&self, v: &ApInt) -> Option<ApInt> {
+		self.clone().into_checked_udiv(v).ok()
+	}
+}
+
+/// Maps to the **unsigned** remainder. See `CheckedDiv` above.
+#[cfg(feature = "num-traits")]
+impl CheckedRem for ApInt {
+	fn checked_rem(&self, v: &ApInt) -> Option<ApInt> {
+		self.clone().into_checked_urem(v).ok()
+	}
+}
+
+#[cfg(feature = "num-traits")]
+impl CheckedNeg for ApInt {
+	fn checked_neg(&self) -> Option<ApInt> {
+		if *self == ApInt::signed_min_value(self.width()) {
+			return None
+		}
+		Some(self.clone().into_negate())
+	}
+}
+
+#[cfg(all(test, feature = "num-traits"))]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn zero_and_one_are_distinct_default_width_values() {
+		assert!(<ApInt as Zero>::zero().is_zero());
+		assert!(!<ApInt as One>::one().is_zero());
+		assert_eq!(<ApInt as Zero>::zero().width(), default_width());
+	}
+
+	#[test]
+	fn bounded_matches_unsigned_min_max() {
+		assert_eq!(<ApInt as Bounded>::min_value(), ApInt::zero(default_width()));
+		assert_eq!(<ApInt as Bounded>::max_value(), ApInt::all_set(default_width()));
+	}
+
+	#[test]
+	fn checked_add_rejects_unmatching_widths() {
+		let a = ApInt::one(BitWidth::new(8).unwrap());
+		let b = ApInt::one(BitWidth::new(16).unwrap());
+		assert_eq!(a.checked_add(&b), None);
+	}
+
+	#[test]
+	fn checked_div_rejects_division_by_zero() {
+		let a = ApInt::one(BitWidth::new(8).unwrap());
+		let z = ApInt::zero(BitWidth::new(8).unwrap());
+		assert_eq!(a.checked_div(&z), None);
+	}
+
+	#[test]
+	fn checked_neg_rejects_signed_min_overflow() {
+		let width = BitWidth::new(8).unwrap();
+		let min = ApInt::signed_min_value(width);
+		assert_eq!(min.checked_neg(), None);
+		assert_eq!(ApInt::one(width).checked_neg(), Some(ApInt::one(width).into_negate()));
+	}
+}
@@ -1,4 +1,6 @@
 use crate::{
+    bitwidth::BitWidth,
+    digit,
     mem::{string::String, vec::Vec},
     ApInt,
     Digit,
@@ -27,73 +29,161 @@ const LB_2_36_I3F13: [u16; 35] = [
     41324, 41677, 42020, 42353,
 ];
 
-impl fmt::Binary for ApInt {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        if self.is_zero() {
-            return write!(f, "0")
+/// Returns the number of bits required to represent `value`, i.e. the
+/// position of its highest set bit plus one, or `0` for `value == 0`.
+fn bit_length_u8(value: u8) -> usize {
+    8 - value.leading_zeros() as usize
+}
+
+/// Returns `(a + (b * c)) + carry` and its implied carry, computed through a
+/// `128`-bit intermediate so the multiplication can never silently overflow.
+fn carry_mul_add(
+    a: crate::digit::DigitRepr,
+    b: crate::digit::DigitRepr,
+    c: crate::digit::DigitRepr,
+    carry: crate::digit::DigitRepr,
+) -> (crate::digit::DigitRepr, crate::digit::DigitRepr) {
+    let wide = u128::from(a) + u128::from(b) * u128::from(c) + u128::from(carry);
+    (wide as crate::digit::DigitRepr, (wide >> 64) as crate::digit::DigitRepr)
+}
+
+/// Adds the single limb `n` into `data` at position `0`, propagating the
+/// carry upward and extending `data` by one limb if it overflows the
+/// current length.
+fn add2(data: &mut Vec<crate::digit::DigitRepr>, n: crate::digit::DigitRepr) {
+    let mut carry = n;
+    let mut i = 0;
+    while carry != 0 {
+        if i == data.len() {
+            data.push(0);
         }
-        let mut ds = self.as_digit_slice().iter().rev();
-        while let Some(digit) = ds.next() {
-            if digit.is_zero() {
-                continue
+        let (sum, overflow) = data[i].overflowing_add(carry);
+        data[i] = sum;
+        carry = overflow as crate::digit::DigitRepr;
+        i += 1;
+    }
+}
+
+/// Divides the little-endian limb vector `limbs` in place by `base`,
+/// processing limbs most-significant-first with a `128`-bit running
+/// remainder, and returns the final remainder.
+fn div_rem_digit(limbs: &mut [crate::digit::DigitRepr], base: crate::digit::DigitRepr) -> crate::digit::DigitRepr {
+    let mut rem: u128 = 0;
+    for limb in limbs.iter_mut().rev() {
+        let cur = (rem << 64) | u128::from(*limb);
+        *limb = (cur / u128::from(base)) as crate::digit::DigitRepr;
+        rem = cur % u128::from(base);
+    }
+    rem as crate::digit::DigitRepr
+}
+
+/// Encodes `value` as exactly `width` ASCII digit bytes (most significant
+/// first, zero-padded) in the given `radix`.
+fn radix_digits(mut value: crate::digit::DigitRepr, radix: crate::digit::DigitRepr, width: usize) -> Vec<u8> {
+    let mut out = Vec::with_capacity(width);
+    for _ in 0 .. width {
+        out.push(encode_digit((value % radix) as u64, false));
+        value /= radix;
+    }
+    out.reverse();
+    out
+}
+
+/// Encodes a digit `value` (`0..=35`) as its ASCII byte, in upper or lower case.
+fn encode_digit(value: u64, upper: bool) -> u8 {
+    match value {
+        0..=9 => b'0' + value as u8,
+        _ => (if upper { b'A' } else { b'a' }) + (value as u8 - 10),
+    }
+}
+
+/// Renders `apint`'s magnitude in the given power-of-two `radix` as ASCII
+/// digit bytes with leading zeros stripped (but at least one digit kept for
+/// zero), slicing its bits into `radix.bits_per_digit()`-sized groups from
+/// most to least significant.
+fn pow2_magnitude_bytes(apint: &ApInt, radix: Radix, upper: bool) -> Vec<u8> {
+    let bits_per_digit = radix.bits_per_digit();
+    let width = apint.len_bits();
+    let digits = apint.as_digit_slice();
+    let digit_count = (width + bits_per_digit - 1) / bits_per_digit;
+    let mut buf = Vec::with_capacity(digit_count);
+    for group in (0 .. digit_count).rev() {
+        let mut value: u64 = 0;
+        for b in 0 .. bits_per_digit {
+            let pos = group * bits_per_digit + b;
+            if pos < width {
+                let bit = (digits[pos / digit::BITS].repr() >> (pos % digit::BITS)) & 0x1;
+                value |= bit << b;
             }
-            write!(f, "{:b}", digit)?;
-            break
         }
-        for digit in ds {
-            write!(f, "{:064b}", digit)?
+        buf.push(encode_digit(value, upper));
+    }
+    let first_nonzero = buf.iter().position(|&b| b != b'0').unwrap_or(buf.len() - 1);
+    buf[first_nonzero..].to_vec()
+}
+
+/// Applies `f.precision()` as a minimum digit count to `s` (left-padding
+/// with `'0'`s), then hands the result to `Formatter::pad_integral` so the
+/// standard `#` prefix, `width`, `fill`, `align` and sign-aware `+` controls
+/// are honored around it.
+fn pad_numeric(f: &mut fmt::Formatter, prefix: &str, s: &str) -> fmt::Result {
+    if let Some(precision) = f.precision() {
+        if s.len() < precision {
+            let mut padded = String::with_capacity(precision);
+            for _ in 0 .. precision - s.len() {
+                padded.push('0');
+            }
+            padded.push_str(s);
+            return f.pad_integral(true, prefix, &padded)
         }
-        Ok(())
+    }
+    f.pad_integral(true, prefix, s)
+}
+
+/// Formats `apint` in the given power-of-two `radix`, honoring the standard
+/// `Formatter` controls plus `f.precision()` via `pad_numeric`.
+fn format_pow2_radix(
+    apint: &ApInt,
+    f: &mut fmt::Formatter,
+    radix: Radix,
+    prefix: &str,
+    upper: bool,
+) -> fmt::Result {
+    let s = String::from_utf8(pow2_magnitude_bytes(apint, radix, upper)).unwrap();
+    pad_numeric(f, prefix, &s)
+}
+
+impl fmt::Binary for ApInt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        format_pow2_radix(self, f, Radix::from(2), "0b", false)
     }
 }
 
 impl fmt::Octal for ApInt {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        if self.is_zero() {
-            return write!(f, "0")
-        }
-        unimplemented!()
-        // Ok(())
+        format_pow2_radix(self, f, Radix::from(8), "0o", false)
     }
 }
 
 impl fmt::LowerHex for ApInt {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        if self.is_zero() {
-            return write!(f, "0")
-        }
-        let mut ds = self.as_digit_slice().iter().rev();
-        while let Some(digit) = ds.next() {
-            if digit.is_zero() {
-                continue
-            }
-            write!(f, "{:x}", digit)?;
-            break
-        }
-        for digit in ds {
-            write!(f, "{:016x}", digit)?
-        }
-        Ok(())
+        format_pow2_radix(self, f, Radix::from(16), "0x", false)
     }
 }
 
 impl fmt::UpperHex for ApInt {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        if self.is_zero() {
-            return write!(f, "0")
-        }
-        let mut ds = self.as_digit_slice().iter().rev();
-        while let Some(digit) = ds.next() {
-            if digit.is_zero() {
-                continue
-            }
-            write!(f, "{:X}", digit)?;
-            break
-        }
-        for digit in ds {
-            write!(f, "{:016X}", digit)?
-        }
-        Ok(())
+        format_pow2_radix(self, f, Radix::from(16), "0x", true)
+    }
+}
+
+impl fmt::Display for ApInt {
+    /// Displays `self`'s value in decimal, honoring the standard `Formatter`
+    /// controls (`width`, `fill`, `align`, sign-aware `+`) and `f.precision()`
+    /// as a minimum digit count, same as the radix-specific `fmt` impls
+    /// above. Decimal has no `#`-alternate prefix, so `prefix` is empty.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        pad_numeric(f, "", &self.to_string_radix(10))
     }
 }
 
@@ -201,6 +291,121 @@ impl ApInt {
         Ok(result)
     }
 
+    /// Like `from_str_radix`, but additionally checks that the parsed value
+    /// fits `target_width` and returns it zero-extended to exactly that
+    /// width, instead of whatever width `from_str_radix` would infer.
+    ///
+    /// This is a thin, argument-order-compatible wrapper around
+    /// `from_radix_str`, so unlike `from_str_radix` it does not accept `_`
+    /// digit separators.
+    ///
+    /// # Errors
+    ///
+    /// Same as `from_radix_str`:
+    ///
+    /// - If `input` is empty.
+    /// - If `input` contains a byte that is not a valid digit for `radix`
+    ///   (including `_`).
+    /// - If the parsed value does not fit within `target_width` bits.
+    pub fn from_str_radix_with_width<R, S>(
+        radix: R,
+        input: S,
+        target_width: BitWidth,
+    ) -> Result<ApInt>
+    where
+        R: Into<Radix>,
+        S: AsRef<str>,
+    {
+        ApInt::from_radix_str(target_width, radix.into(), input.as_ref())
+    }
+
+    /// Parses `input` as a big-endian `radix`-encoded integer into an `ApInt`
+    /// of exactly `target_width` bits.
+    ///
+    /// Power-of-two radices are packed `radix.bits_per_digit()` bits per
+    /// character directly into the result. All other radices run a Horner
+    /// multiply-accumulate (`acc = acc * radix + digit`), reusing the
+    /// in-place `checked_mul_assign`/`increment_by` arithmetic at
+    /// `target_width` throughout, so no intermediate resizing is needed.
+    ///
+    /// # Errors
+    ///
+    /// - If `input` is empty.
+    /// - If `input` contains a byte that is not a valid digit for `radix`.
+    /// - If the parsed value does not fit within `target_width` bits. For
+    ///   non-power-of-two radices this is detected via the same
+    ///   never-underestimating bit-count estimate used to pre-size
+    ///   `from_radix_digits`, which is taken from the number of digits alone
+    ///   and can exceed the exact bit count by several bits. Values that do
+    ///   fit are then rejected as well, e.g. `"10"` in radix 10 at a
+    ///   `target_width` of 8 bits.
+    pub fn from_radix_str(target_width: BitWidth, radix: Radix, input: &str) -> Result<ApInt> {
+        if input.is_empty() {
+            return Err(Error::invalid_string_repr(input, radix)
+                .with_annotation("Cannot parse an empty string into an ApInt."))
+        }
+
+        let mut decoded = Vec::with_capacity(input.len());
+        for (i, b) in input.bytes().enumerate() {
+            let d = match b {
+                b'0'..=b'9' => b - b'0',
+                b'a'..=b'z' => b - b'a' + 10,
+                b'A'..=b'Z' => b - b'A' + 10,
+                _ => ::core::u8::MAX,
+            };
+            if !radix.is_valid_byte(d) {
+                return Err(Error::invalid_char_in_string_repr(
+                    input,
+                    radix,
+                    i,
+                    char::from(b),
+                ))
+            }
+            decoded.push(d);
+        }
+
+        if radix.is_power_of_two() {
+            let bits_per_digit = radix.bits_per_digit();
+            let first_significant = decoded.iter()
+                .position(|&d| d != 0)
+                .unwrap_or(decoded.len() - 1);
+            let required_bits = (decoded.len() - first_significant - 1) * bits_per_digit
+                + bit_length_u8(decoded[first_significant]);
+            if required_bits > target_width.to_usize() {
+                return Err(Error::invalid_string_repr(input, radix)
+                    .with_annotation("The value does not fit within the given target width."))
+            }
+            let mut result = ApInt::zero(target_width);
+            // Start at the first significant digit and skip the shift before
+            // it, so no shift by `bits_per_digit` is ever attempted on a
+            // `target_width` that is not wider than that.
+            for (i, &d) in decoded[first_significant..].iter().enumerate() {
+                if i != 0 {
+                    result.checked_shl_assign(bits_per_digit).unwrap();
+                }
+                result.increment_by(d as u64);
+            }
+            Ok(result)
+        } else {
+            let estimated_bits = (LB_2_36_I3F13[(radix.to_u8() - 2) as usize] as usize)
+                .checked_mul(decoded.len() + 1)
+                .unwrap()
+                >> 13;
+            if estimated_bits > target_width.to_usize() {
+                return Err(Error::invalid_string_repr(input, radix)
+                    .with_annotation("The value does not fit within the given target width."))
+            }
+            let mut radix_val = ApInt::zero(target_width);
+            radix_val.increment_by(radix.to_u8() as u64);
+            let mut acc = ApInt::zero(target_width);
+            for &d in &decoded {
+                acc.checked_mul_assign(&radix_val).unwrap();
+                acc.increment_by(d as u64);
+            }
+            Ok(acc)
+        }
+    }
+
     // Convert from a power of two radix (bits == ilog2(radix)) where bits evenly
     // divides Digit::BITS.
     //
@@ -215,7 +420,7 @@ impl ApInt {
 
         let radix_digits_per_digit = Digit::BITS / bits;
 
-        let data = v
+        let mut data: Vec<Digit> = v
             .chunks(radix_digits_per_digit)
             .map(|chunk| {
                 chunk
@@ -223,7 +428,15 @@ impl ApInt {
                     .rev()
                     .fold(0, |acc, &c| (acc << bits) | DigitRepr::from(c))
             })
-            .map(Digit);
+            .map(Digit)
+            .collect();
+
+        // Drop most-significant all-zero limbs so the result deterministically
+        // comes out at the minimal limb count for its magnitude, mirroring the
+        // trim in `from_radix_digits`.
+        while data.len() > 1 && data.last().unwrap().repr() == 0 {
+            data.pop();
+        }
 
         ApInt::from_iter(data).unwrap()
     }
@@ -267,15 +480,19 @@ impl ApInt {
             data.push(Digit(d));
         }
 
+        // Drop most-significant all-zero limbs so the result deterministically
+        // comes out at the minimal limb count for its magnitude, mirroring the
+        // trim in `from_radix_digits`.
+        while data.len() > 1 && data.last().unwrap().repr() == 0 {
+            data.pop();
+        }
+
         ApInt::from_iter(data).unwrap()
     }
 
     // Read little-endian radix digits.
     //
     // Forked from: https://github.com/rust-num/num/blob/master/bigint/src/biguint.rs#L177
-    //
-    // TODO: This does not work, yet. Some parts of the algorithm are
-    //       commented-out since the required functionality does not exist, yet.
     fn from_radix_digits(v: &[u8], radix: Radix) -> ApInt {
         use crate::digit::DigitRepr;
 
@@ -289,9 +506,9 @@ impl ApInt {
             .unwrap()
             >> 13;
         let big_digits = (bits / Digit::BITS) + 1;
-        let mut data = Vec::with_capacity(big_digits as usize);
+        let mut data: Vec<DigitRepr> = Vec::with_capacity(big_digits as usize);
 
-        let (_base, power) = radix.get_radix_base();
+        let (base, power) = radix.get_radix_base();
         let radix = DigitRepr::from(radix.to_u8());
 
         let r = v.len() % power;
@@ -309,48 +526,93 @@ impl ApInt {
                 data.push(0);
             }
 
-            let carry = 0;
-            for _d in &mut data {
-                // *d = mac_with_carry(0, *d, base, &mut carry); // TODO! This
-                // was commented out.
-
-                // // fn carry_mul_add(a: Digit, b: Digit, c: Digit, carry:
-                // Digit) -> DigitAndCarry // Returns the result
-                // of `(a + (b * c)) + carry` and its implied carry value.
-
-                // let DigitAndCarry(d, carry) = carry_mul_add(digit::ZERO, *d,
-                // base, carry); // TODO! This was commented out.
+            let mut carry: DigitRepr = 0;
+            for d in &mut data {
+                let (low, next_carry) = carry_mul_add(0, *d, base, carry);
+                *d = low;
+                carry = next_carry;
             }
             debug_assert!(carry == 0);
 
-            let _n = chunk
+            let n = chunk
                 .iter()
                 .fold(0, |acc, &d| acc * radix + DigitRepr::from(d));
-            // add2(&mut data, &[n]); // TODO: This was commented out.
+            add2(&mut data, n);
+        }
+
+        // The pre-multiply `data.push(0)` above reserves headroom for a
+        // carry that may never materialize; trim it back off so the result
+        // always comes out at the minimal limb count for its magnitude.
+        while data.len() > 1 && *data.last().unwrap() == 0 {
+            data.pop();
         }
 
         ApInt::from_iter(data.into_iter().map(Digit)).unwrap()
     }
 }
 
+impl core::str::FromStr for ApInt {
+    type Err = Error;
+
+    /// Parses `input` as a decimal (base-10) string into an `ApInt` sized to
+    /// the minimum `BitWidth` that fits the value, via `from_str_radix`.
+    fn from_str(input: &str) -> Result<ApInt> {
+        ApInt::from_str_radix(10, input)
+    }
+}
+
 //  =======================================================================
 ///  Serialization
 /// =======================================================================
 impl ApInt {
-    /// Returns a `String` representation of the binary encoded `ApInt` for the
-    /// given `Radix`.
+    /// Returns a `String` representation of `self`'s magnitude for the
+    /// given `Radix`, with no prefix and no leading zeros (except for the
+    /// value zero itself, which is printed as `"0"`).
     pub fn to_string_radix<R>(&self, radix: R) -> String
     where
         R: Into<Radix>,
     {
+        use crate::digit::DigitRepr;
+
         let radix = radix.into();
 
-        if radix != Radix::from(16) {
-            unimplemented!();
-        } else  {
+        if radix.is_power_of_two() {
+            String::from_utf8(pow2_magnitude_bytes(self, radix, false)).unwrap()
+        } else {
+            let (base, power) = radix.get_radix_base();
+            let radix_repr = DigitRepr::from(radix.to_u8());
+            let mut limbs: Vec<DigitRepr> =
+                self.as_digit_slice().iter().map(|d| d.repr()).collect();
+
+            // Repeatedly divide the whole magnitude by `base`, the largest
+            // power of `radix` that fits a single `Digit`; each remainder is
+            // one `power`-wide group of textual digits, least significant
+            // group first.
+            let mut groups = Vec::new();
+            loop {
+                let rem = div_rem_digit(&mut limbs, base);
+                groups.push(rem);
+                while limbs.len() > 1 && *limbs.last().unwrap() == 0 {
+                    limbs.pop();
+                }
+                if limbs.len() == 1 && limbs[0] == 0 {
+                    break
+                }
+            }
+
             let mut s = String::new();
-            for digit in self.as_digit_slice().to_owned().into_iter().rev() {
-                s.push_str(&format!("{:016x}", digit));
+            for (i, &group) in groups.iter().enumerate().rev() {
+                let digits = radix_digits(group, radix_repr, power);
+                if i == groups.len() - 1 {
+                    // Top group: strip leading zeros, but always keep at
+                    // least one digit.
+                    let first_nonzero = digits.iter()
+                        .position(|&b| b != b'0')
+                        .unwrap_or(digits.len() - 1);
+                    s.push_str(core::str::from_utf8(&digits[first_nonzero..]).unwrap());
+                } else {
+                    s.push_str(core::str::from_utf8(&digits).unwrap());
+                }
             }
             s
         }
@@ -476,6 +738,74 @@ mod tests {
         }
     }
 
+    mod octal {
+        use super::*;
+
+        fn assert_octal(val: ApInt, expected: &str) {
+            assert_eq!(format!("{:o}", val), expected)
+        }
+
+        #[test]
+        fn small() {
+            assert_octal(ApInt::zero(BitWidth::w32()), "0");
+            assert_octal(ApInt::from(0o17_u32), "17");
+            assert_octal(ApInt::all_set(BitWidth::w32()), "37777777777");
+        }
+    }
+
+    mod formatter_controls {
+        use super::*;
+
+        #[test]
+        fn hex_alternate_zero_padded_width() {
+            let x = ApInt::from(0x1A_u32);
+            assert_eq!(format!("{:#010x}", x), "0x0000001a");
+        }
+
+        #[test]
+        fn hex_alternate_no_padding_needed() {
+            let x = ApInt::all_set(BitWidth::w32());
+            assert_eq!(format!("{:#010x}", x), "0xffffffff");
+        }
+
+        #[test]
+        fn binary_right_aligned_with_fill() {
+            let x = ApInt::from(0b101_u32);
+            assert_eq!(format!("{:->12b}", x), "---------101");
+        }
+
+        #[test]
+        fn octal_alternate_prefix() {
+            let x = ApInt::from(0o17_u32);
+            assert_eq!(format!("{:#o}", x), "0o17");
+        }
+
+        #[test]
+        fn octal_sign_plus() {
+            let x = ApInt::from(0o17_u32);
+            assert_eq!(format!("{:+o}", x), "+17");
+        }
+
+        #[test]
+        fn hex_centered_alignment() {
+            let x = ApInt::from(0x1A_u32);
+            assert_eq!(format!("{:^7x}", x), "  1a   ");
+        }
+
+        #[test]
+        fn hex_precision_zero_pads_digits() {
+            let x = ApInt::from(0x1A_u32);
+            assert_eq!(format!("{:#.6x}", x), "0x00001a");
+            assert_eq!(format!("{:.6x}", x), "00001a");
+        }
+
+        #[test]
+        fn hex_precision_smaller_than_width_is_then_aligned() {
+            let x = ApInt::from(0x1A_u32);
+            assert_eq!(format!("{:->8.4x}", x), "----001a");
+        }
+    }
+
     mod from_str_radix {
 
         use super::*;
@@ -585,8 +915,9 @@ mod tests {
                 (10, "42", 42),
                 (10, "1337", 1337),
                 (10, "5_000_000", 5_000_000),
-                // (10, "18_446_744_073_709_551_615", u64::max_value()), // Does not
-                // work, yet!
+                (10, "18_446_744_073_709_551_615", u64::max_value()),
+                (7, "666666666666666666666", 558545864083284006),
+                (36, "zzzzzzzzzzzz", 4738381338321616895),
                 (16, "100", 0x100),
                 (16, "42", 0x42),
                 (16, "1337", 0x1337),
@@ -604,5 +935,227 @@ mod tests {
                 assert_eq!(result, expected)
             }
         }
+
+        #[test]
+        fn trims_leading_zero_limbs_across_multiple_limbs() {
+            use crate::traits::Width;
+
+            // 192 leading zero bits followed by a `1` spans more than one
+            // 64-bit limb; the result should still trim down to a single
+            // limb, same as a bare "1" would.
+            let padded = format!("{}1", "0".repeat(192));
+            let result = ApInt::from_str_radix(2, &padded).unwrap();
+            let expected = ApInt::from_str_radix(2, "1").unwrap();
+            assert_eq!(result, expected);
+            assert_eq!(result.width(), expected.width());
+        }
+
+        #[test]
+        fn rejects_out_of_range_digit() {
+            let radix = Radix::new(2).unwrap();
+            let input = "12";
+            assert_eq!(
+                ApInt::from_str_radix(radix, input),
+                Err(Error::invalid_char_in_string_repr(input, radix, 1, '2'))
+            );
+        }
+    }
+
+    mod from_str_radix_with_width {
+        use super::*;
+
+        #[test]
+        fn fits_and_zero_extends() {
+            let result =
+                ApInt::from_str_radix_with_width(16, "ff", BitWidth::new(32).unwrap()).unwrap();
+            let mut expected = ApInt::zero(BitWidth::new(32).unwrap());
+            expected.increment_by(0xff);
+            assert_eq!(result, expected);
+        }
+
+        #[test]
+        fn rejects_value_that_does_not_fit() {
+            assert!(ApInt::from_str_radix_with_width(16, "ff", BitWidth::new(4).unwrap()).is_err());
+        }
+
+        #[test]
+        fn agrees_with_from_radix_str() {
+            let width = BitWidth::new(32).unwrap();
+            let radix = Radix::new(16).unwrap();
+            assert_eq!(
+                ApInt::from_str_radix_with_width(radix, "dead", width),
+                ApInt::from_radix_str(width, radix, "dead"),
+            );
+        }
+    }
+
+    mod from_radix_str {
+        use super::*;
+
+        fn expected_value(width: BitWidth, value: u64) -> ApInt {
+            let mut result = ApInt::zero(width);
+            result.increment_by(value);
+            result
+        }
+
+        #[test]
+        fn empty() {
+            let radix = Radix::new(10).unwrap();
+            assert_eq!(
+                ApInt::from_radix_str(BitWidth::new(32).unwrap(), radix, ""),
+                Err(Error::invalid_string_repr("", radix).with_annotation(
+                    "Cannot parse an empty string into an ApInt."
+                ))
+            )
+        }
+
+        #[test]
+        fn small_values() {
+            let width = BitWidth::new(32).unwrap();
+            let samples = vec![
+                // (Radix, Input String, Expected Value)
+                (2, "0", 0),
+                (2, "1010", 10),
+                (8, "17", 15),
+                (10, "0", 0),
+                (10, "1337", 1337),
+                (16, "ff", 255),
+                (16, "FF", 255),
+                (36, "z", 35),
+            ];
+            for sample in &samples {
+                let radix = Radix::new(sample.0).unwrap();
+                let result = ApInt::from_radix_str(width, radix, sample.1).unwrap();
+                assert_eq!(result, expected_value(width, sample.2))
+            }
+        }
+
+        #[test]
+        fn rejects_value_too_large_for_target_width() {
+            let width = BitWidth::new(4).unwrap();
+            assert!(ApInt::from_radix_str(width, Radix::new(2).unwrap(), "10000").is_err());
+            assert!(ApInt::from_radix_str(width, Radix::new(10).unwrap(), "16").is_err());
+        }
+
+        #[test]
+        fn rejects_invalid_digit() {
+            let width = BitWidth::new(8).unwrap();
+            assert!(ApInt::from_radix_str(width, Radix::new(2).unwrap(), "102").is_err());
+        }
+
+        #[test]
+        fn round_trips_through_formatting_in_several_radices() {
+            let width = BitWidth::new(32).unwrap();
+            let samples = [0u64, 1, 42, 255, 1337, 0xDEAD_BEEF, u32::max_value() as u64];
+            for &value in &samples {
+                let expected = expected_value(width, value);
+
+                let binary = format!("{:b}", expected);
+                let from_binary = ApInt::from_radix_str(
+                    width,
+                    Radix::new(2).unwrap(),
+                    binary.trim_start_matches("0b"),
+                ).unwrap();
+                assert_eq!(from_binary, expected);
+
+                let octal = format!("{:o}", expected);
+                let from_octal = ApInt::from_radix_str(
+                    width,
+                    Radix::new(8).unwrap(),
+                    octal.trim_start_matches("0o"),
+                ).unwrap();
+                assert_eq!(from_octal, expected);
+
+                let hex = format!("{:x}", expected);
+                let from_hex = ApInt::from_radix_str(
+                    width,
+                    Radix::new(16).unwrap(),
+                    hex.trim_start_matches("0x"),
+                ).unwrap();
+                assert_eq!(from_hex, expected);
+            }
+        }
+    }
+
+    mod to_string_radix {
+        use super::*;
+
+        #[test]
+        fn zero_is_zero_in_every_radix() {
+            for &r in &[2, 7, 8, 10, 16, 36] {
+                let radix = Radix::new(r).unwrap();
+                assert_eq!(ApInt::zero(BitWidth::new(32).unwrap()).to_string_radix(radix), "0");
+            }
+        }
+
+        #[test]
+        fn round_trips_through_from_str_radix() {
+            let width = BitWidth::new(64).unwrap();
+            let samples: [u64; 5] = [0, 1, 42, 1337, u64::max_value()];
+            for &r in &[2, 8, 10, 16, 36] {
+                let radix = Radix::new(r).unwrap();
+                for &value in &samples {
+                    let mut apint = ApInt::zero(width);
+                    apint.increment_by(value);
+                    let s = apint.to_string_radix(radix);
+                    let parsed = ApInt::from_str_radix(radix, &s).unwrap();
+                    assert_eq!(parsed, apint);
+                }
+            }
+        }
+
+        #[test]
+        fn general_radix_matches_hex() {
+            let apint = ApInt::from_u64(0xDEAD_BEEF_0000_1234);
+            assert_eq!(apint.to_string_radix(Radix::new(16).unwrap()), "deadbeef00001234");
+        }
+
+        #[test]
+        fn decimal_strips_leading_zero_limbs() {
+            let width = BitWidth::new(192).unwrap();
+            let mut apint = ApInt::zero(width);
+            apint.increment_by(42);
+            assert_eq!(apint.to_string_radix(10), "42");
+        }
+    }
+
+    mod display {
+        use super::*;
+
+        #[test]
+        fn zero() {
+            assert_eq!(format!("{}", ApInt::zero(BitWidth::w32())), "0");
+        }
+
+        #[test]
+        fn max_width_value() {
+            assert_eq!(
+                format!("{}", ApInt::all_set(BitWidth::new(8).unwrap())),
+                "255"
+            );
+        }
+
+        #[test]
+        fn honors_width_and_precision() {
+            let x = ApInt::from(42_u32);
+            assert_eq!(format!("{:6}", x), "    42");
+            assert_eq!(format!("{:06}", x), "000042");
+            assert_eq!(format!("{:.4}", x), "0042");
+        }
+
+        #[test]
+        fn round_trips_large_values_through_from_str() {
+            let width = BitWidth::new(128).unwrap();
+            let mut apint = ApInt::zero(width);
+            apint.increment_by(u64::max_value());
+            apint.checked_mul_assign(&{
+                let mut m = ApInt::zero(width);
+                m.increment_by(u64::max_value());
+                m
+            }).unwrap();
+            let s = format!("{}", apint);
+            let parsed = s.parse::<ApInt>().unwrap();
+            assert_eq!(parsed, apint);
+        }
     }
 }
@@ -1,10 +1,18 @@
 use apint::{ApInt};
 use apint::utils::{DataAccessMut};
 use errors::{Result};
+use traits::{Width};
 use checks;
 use digit;
 use digit::{Digit};
 
+/// Returns `true` if the bit at `width - 1` is set within the little-endian `digits`.
+fn ext_sign_bit(digits: &[Digit], width: usize) -> bool {
+	let idx = (width - 1) / digit::BITS;
+	let bit = (width - 1) % digit::BITS;
+	(digits[idx].repr() >> bit) & 0x1 == 0x1
+}
+
 /// Represents an amount of bits to shift a value like an `ApInt`.
 /// 
 /// The purpose of this type is to create a generic abstraction
@@ -134,8 +142,23 @@ impl ApInt {
 			DataAccessMut::Inl(digit) => {
 				*digit.repr_mut() >>= shift_amount.to_usize();
 			}
-			DataAccessMut::Ext(_digits) => {
-				unimplemented!()
+			DataAccessMut::Ext(digits) => {
+				let digit_steps = shift_amount.digit_steps();
+				if digit_steps != 0 {
+					let digits_len = digits.len();
+					digits.rotate(digit_steps);
+					digits[(digits_len - digit_steps)..].iter_mut().for_each(|d| *d = Digit::zero());
+				}
+				let bit_steps = shift_amount.bit_steps();
+				if bit_steps != 0 {
+					let mut carry = 0;
+					for elem in digits.iter_mut().rev() {
+						let repr = elem.repr();
+						let new_carry = repr << (digit::BITS - bit_steps);
+						*elem = Digit((repr >> bit_steps) | carry);
+						carry = new_carry;
+					}
+				}
 			}
 		}
 		Ok(())
@@ -169,16 +192,41 @@ impl ApInt {
 	{
 		let shift_amount = shift_amount.into();
 		checks::verify_shift_amount(self, shift_amount)?;
+		let width = self.width().to_usize();
 		match self.access_data_mut() {
 			DataAccessMut::Inl(digit) => {
 				let signed = digit.repr() as i64;
 				let shifted = signed >> shift_amount.to_usize();
 				*digit.repr_mut() = shifted as u64;
 			}
-			DataAccessMut::Ext(_digits) => {
-				unimplemented!()
+			DataAccessMut::Ext(digits) => {
+				let fill = if ext_sign_bit(digits, width) { !0 } else { 0 };
+				// Sign-extend into the unused high bits of the top digit so the
+				// bit-step loop below shifts sign bits, not zeros, into the value.
+				let used_top_bits = width % digit::BITS;
+				if used_top_bits != 0 {
+					let last = digits.len() - 1;
+					digits[last] = Digit(digits[last].repr() | (fill << used_top_bits));
+				}
+				let digit_steps = shift_amount.digit_steps();
+				if digit_steps != 0 {
+					let digits_len = digits.len();
+					digits.rotate(digit_steps);
+					digits[(digits_len - digit_steps)..].iter_mut().for_each(|d| *d = Digit(fill));
+				}
+				let bit_steps = shift_amount.bit_steps();
+				if bit_steps != 0 {
+					let mut carry = fill << (digit::BITS - bit_steps);
+					for elem in digits.iter_mut().rev() {
+						let repr = elem.repr();
+						let new_carry = repr << (digit::BITS - bit_steps);
+						*elem = Digit((repr >> bit_steps) | carry);
+						carry = new_carry;
+					}
+				}
 			}
 		}
+		self.clear_unused_bits();
 		Ok(())
 	}
 
@@ -221,4 +269,28 @@ mod tests {
 		let mut x = ApInt::from_u128(0x0123_4567_89AB_CDEF_0011_2233_4455_6677);
 		assert!(x.checked_shl_assign(128).is_err())
 	}
+
+	#[test]
+	fn checked_lshr_assign_ok() {
+		let repr: u128 = 0x0123_4567_89AB_CDEF_0011_2233_4455_6677;
+		let x = ApInt::from_u128(repr);
+		for shamt in 0..128 {
+			let expected = ApInt::from_u128(repr >> shamt);
+			let mut result = x.clone();
+			result.checked_lshr_assign(shamt).unwrap();
+			assert_eq!(result, expected);
+		}
+	}
+
+	#[test]
+	fn checked_ashr_assign_ok() {
+		let repr: i128 = -0x0123_4567_89AB_CDEF_0011_2233_4455_6677;
+		let x = ApInt::from_i128(repr);
+		for shamt in 0..128 {
+			let expected = ApInt::from_i128(repr >> shamt);
+			let mut result = x.clone();
+			result.checked_ashr_assign(shamt).unwrap();
+			assert_eq!(result, expected);
+		}
+	}
 }
@@ -1,4 +1,5 @@
 use errors::{Error, Result};
+use digit::{DigitRepr};
 
 /// A radix for parsing strings as `APInt`s.
 /// 
@@ -29,7 +30,7 @@ impl Radix {
 	/// - If the given value is not within the valid radix range of `2..36`.
 	#[inline]
 	pub fn new(radix: u8) -> Result<Radix> {
-		if !(Radix::MIN <= radix && radix >= Radix::MAX) {
+		if !(Radix::MIN <= radix && radix <= Radix::MAX) {
 			return Err(Error::invalid_radix(radix))
 		}
 		Ok(Radix(radix))
@@ -72,11 +73,45 @@ impl Radix {
 	pub(crate) fn bits_per_digit(self) -> usize {
 		assert!(self.is_power_of_two());
 		fn find_last_bit_set(val: u8) -> usize {
-			::std::mem::size_of::<u8>() * 8 - val.leading_zeros() as usize
+			::core::mem::size_of::<u8>() * 8 - val.leading_zeros() as usize
 		}
 		find_last_bit_set(self.to_u8()) - 1
 	}
 
+	/// Returns `Some(bits_per_digit)` if this `Radix` is a power of two, i.e.
+	/// if a single digit can be stored in an exact, fixed number of bits, or
+	/// `None` otherwise.
+	///
+	/// This is the non-panicking counterpart to `bits_per_digit`, meant for
+	/// call sites that need to branch between the power-of-two bitwise digit
+	/// packing and the general (non-power-of-two) radix algorithms.
+	#[inline]
+	pub(crate) fn exact_bits_per_digit(self) -> Option<usize> {
+		if self.is_power_of_two() {
+			Some(self.bits_per_digit())
+		} else {
+			None
+		}
+	}
+
+	/// Returns `(base, power)` where `base` is the largest power of this
+	/// `Radix` that still fits into a single `Digit`, and `power` is the
+	/// exponent such that `base == radix^power`.
+	///
+	/// This is used to process as many radix digits as possible per `Digit`
+	/// multiplication when parsing or printing a non-power-of-two `Radix`.
+	#[inline]
+	pub(crate) fn get_radix_base(self) -> (DigitRepr, usize) {
+		let radix = DigitRepr::from(self.to_u8());
+		let mut power = 1;
+		let mut base = radix;
+		while let Some(next) = base.checked_mul(radix) {
+			base = next;
+			power += 1;
+		}
+		(base, power)
+	}
+
 }
 
 impl From<u8> for Radix {
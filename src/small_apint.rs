@@ -1,13 +1,13 @@
 use digit;
-use digit::{Bit, Digit};
+use digit::{Bit, Digit, DigitRepr};
 use bitwidth::BitWidth;
-use errors::{Result};
+use errors::{DivOp, Error, Result};
 use traits::{
 	Width,
 	APIntImpl,
 	APIntMutImpl,
 };
-use std::ops::{
+use core::ops::{
 	BitAndAssign,
 	BitOrAssign,
 	BitXorAssign
@@ -228,48 +228,277 @@ impl<T> APIntMutImpl<SmallAPInt> for T
 
 	fn neg_inplace(&mut self) {
 		// Negating a twos-complement number is accomplished by inverting all bits and adding 1.
-		unimplemented!()
+		let width = self.width().to_usize();
+		self.digit_mut().not_inplace();
+		*self.digit_mut().repr_mut() = self.digit().repr().wrapping_add(1);
+		self.digit_mut().retain_last_n(width).unwrap();
 	}
 
 	fn add_inplace(&mut self, other: &SmallAPInt) -> Result<()> {
-		unimplemented!()
+		checks::verify_common_bitwidth(self, &other)?;
+		let width = self.width().to_usize();
+		let result = self.digit().repr().wrapping_add(other.digit().repr());
+		*self.digit_mut().repr_mut() = result;
+		self.digit_mut().retain_last_n(width).unwrap();
+		Ok(())
 	}
 
 	fn sub_inplace(&mut self, other: &SmallAPInt) -> Result<()> {
-		unimplemented!()
+		checks::verify_common_bitwidth(self, &other)?;
+		let width = self.width().to_usize();
+		let result = self.digit().repr().wrapping_sub(other.digit().repr());
+		*self.digit_mut().repr_mut() = result;
+		self.digit_mut().retain_last_n(width).unwrap();
+		Ok(())
 	}
 
 	fn mul_inplace(&mut self, other: &SmallAPInt) -> Result<()> {
-		unimplemented!()
+		checks::verify_common_bitwidth(self, &other)?;
+		let width = self.width().to_usize();
+		let result = self.digit().repr().wrapping_mul(other.digit().repr());
+		*self.digit_mut().repr_mut() = result;
+		self.digit_mut().retain_last_n(width).unwrap();
+		Ok(())
 	}
 
 	fn sdiv_inplace(&mut self, other: &SmallAPInt) -> Result<()> {
-		unimplemented!()
+		checks::verify_common_bitwidth(self, &other)?;
+		if other.digit().is_zero() {
+			return Err(Error::division_by_zero(DivOp::SignedDiv, *other))
+		}
+		let width = self.width().to_usize();
+		let inflate_abs = digit::BITS - width;
+		let left  = (self.digit().repr()  << inflate_abs) as i64;
+		let right = (other.digit().repr() << inflate_abs) as i64;
+		let result = left / right;
+		*self.digit_mut().repr_mut() = result as DigitRepr;
+		self.digit_mut().retain_last_n(width).unwrap();
+		Ok(())
 	}
 
 	fn udiv_inplace(&mut self, other: &SmallAPInt) -> Result<()> {
-		unimplemented!()
+		checks::verify_common_bitwidth(self, &other)?;
+		if other.digit().is_zero() {
+			return Err(Error::division_by_zero(DivOp::UnsignedDiv, *other))
+		}
+		let width = self.width().to_usize();
+		let result = self.digit().repr() / other.digit().repr();
+		*self.digit_mut().repr_mut() = result;
+		self.digit_mut().retain_last_n(width).unwrap();
+		Ok(())
 	}
 
 	fn srem_inplace(&mut self, other: &SmallAPInt) -> Result<()> {
-		unimplemented!()
+		checks::verify_common_bitwidth(self, &other)?;
+		if other.digit().is_zero() {
+			return Err(Error::division_by_zero(DivOp::SignedRem, *other))
+		}
+		let width = self.width().to_usize();
+		let inflate_abs = digit::BITS - width;
+		let left  = (self.digit().repr()  << inflate_abs) as i64;
+		let right = (other.digit().repr() << inflate_abs) as i64;
+		let result = (left % right) >> inflate_abs;
+		*self.digit_mut().repr_mut() = result as DigitRepr;
+		self.digit_mut().retain_last_n(width).unwrap();
+		Ok(())
 	}
 
 	fn urem_inplace(&mut self, other: &SmallAPInt) -> Result<()> {
-		unimplemented!()
+		checks::verify_common_bitwidth(self, &other)?;
+		if other.digit().is_zero() {
+			return Err(Error::division_by_zero(DivOp::UnsignedRem, *other))
+		}
+		let width = self.width().to_usize();
+		let result = self.digit().repr() % other.digit().repr();
+		*self.digit_mut().repr_mut() = result;
+		self.digit_mut().retain_last_n(width).unwrap();
+		Ok(())
 	}
 
 
 	fn shl_inplace(&mut self, other: &SmallAPInt) -> Result<()> {
-		unimplemented!()
+		let width = self.width().to_usize();
+		let shamt = other.digit().repr() as usize;
+		if shamt >= width {
+			self.unset_all();
+		} else {
+			let result = self.digit().repr() << shamt;
+			*self.digit_mut().repr_mut() = result;
+			self.digit_mut().retain_last_n(width).unwrap();
+		}
+		Ok(())
 	}
 
 	fn lshr_inplace(&mut self, other: &SmallAPInt) -> Result<()> {
-		unimplemented!()
+		let width = self.width().to_usize();
+		let shamt = other.digit().repr() as usize;
+		if shamt >= width {
+			self.unset_all();
+		} else {
+			let result = self.digit().repr() >> shamt;
+			*self.digit_mut().repr_mut() = result;
+		}
+		Ok(())
 	}
 
 	fn ashr_inplace(&mut self, other: &SmallAPInt) -> Result<()> {
-		unimplemented!()
+		let width = self.width().to_usize();
+		let shamt = other.digit().repr() as usize;
+		let is_negative = (self.digit().repr() >> (width - 1)) & 0x1 == 0x1;
+		if shamt >= width {
+			if is_negative {
+				self.set_all();
+			} else {
+				self.unset_all();
+			}
+		} else {
+			let inflate_abs = digit::BITS - width;
+			let inflated = (self.digit().repr() << inflate_abs) as i64;
+			let shifted = inflated >> (inflate_abs + shamt);
+			*self.digit_mut().repr_mut() = shifted as DigitRepr;
+			self.digit_mut().retain_last_n(width).unwrap();
+		}
+		Ok(())
+	}
+
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn add_inplace_wraps_at_width() {
+		let mut digit = Digit(200);
+		let mut lhs = SmallAPIntMut::new(BitWidth::new(8).unwrap(), &mut digit);
+		let rhs = SmallAPInt::new(BitWidth::new(8).unwrap(), Digit(100));
+		lhs.add_inplace(&rhs).unwrap();
+		assert_eq!(lhs.digit().repr(), 44);
+	}
+
+	#[test]
+	fn sub_inplace_wraps_at_width() {
+		let mut digit = Digit(10);
+		let mut lhs = SmallAPIntMut::new(BitWidth::new(8).unwrap(), &mut digit);
+		let rhs = SmallAPInt::new(BitWidth::new(8).unwrap(), Digit(20));
+		lhs.sub_inplace(&rhs).unwrap();
+		assert_eq!(lhs.digit().repr(), 246);
+	}
+
+	#[test]
+	fn mul_inplace_wraps_at_width() {
+		let mut digit = Digit(100);
+		let mut lhs = SmallAPIntMut::new(BitWidth::new(8).unwrap(), &mut digit);
+		let rhs = SmallAPInt::new(BitWidth::new(8).unwrap(), Digit(3));
+		lhs.mul_inplace(&rhs).unwrap();
+		assert_eq!(lhs.digit().repr(), 44);
+	}
+
+	#[test]
+	fn neg_inplace_wraps_at_width() {
+		let mut digit = Digit(1);
+		let mut val = SmallAPIntMut::new(BitWidth::new(8).unwrap(), &mut digit);
+		val.neg_inplace();
+		assert_eq!(val.digit().repr(), 255);
+	}
+
+	#[test]
+	fn sdiv_inplace_rounds_toward_zero() {
+		// -7 as an 8-bit two's-complement value is 249.
+		let mut digit = Digit(249);
+		let mut lhs = SmallAPIntMut::new(BitWidth::new(8).unwrap(), &mut digit);
+		let rhs = SmallAPInt::new(BitWidth::new(8).unwrap(), Digit(2));
+		lhs.sdiv_inplace(&rhs).unwrap();
+		// -3 as an 8-bit two's-complement value is 253.
+		assert_eq!(lhs.digit().repr(), 253);
+	}
+
+	#[test]
+	fn srem_inplace_rounds_toward_zero() {
+		// -7 as a 16-bit two's-complement value is 65529.
+		let mut digit = Digit(65529);
+		let mut lhs = SmallAPIntMut::new(BitWidth::new(16).unwrap(), &mut digit);
+		let rhs = SmallAPInt::new(BitWidth::new(16).unwrap(), Digit(2));
+		lhs.srem_inplace(&rhs).unwrap();
+		// -1 as a 16-bit two's-complement value is 65535.
+		assert_eq!(lhs.digit().repr(), 65535);
+	}
+
+	#[test]
+	fn udiv_inplace_divides_unsigned() {
+		let mut digit = Digit(200);
+		let mut lhs = SmallAPIntMut::new(BitWidth::new(8).unwrap(), &mut digit);
+		let rhs = SmallAPInt::new(BitWidth::new(8).unwrap(), Digit(7));
+		lhs.udiv_inplace(&rhs).unwrap();
+		assert_eq!(lhs.digit().repr(), 28);
+	}
+
+	#[test]
+	fn udiv_inplace_errors_on_zero() {
+		let mut digit = Digit(200);
+		let mut lhs = SmallAPIntMut::new(BitWidth::new(8).unwrap(), &mut digit);
+		let rhs = SmallAPInt::new(BitWidth::new(8).unwrap(), Digit(0));
+		assert!(lhs.udiv_inplace(&rhs).is_err());
+	}
+
+	#[test]
+	fn shl_inplace_masks_overflowing_bits() {
+		let mut digit = Digit(0b0000_0011);
+		let mut lhs = SmallAPIntMut::new(BitWidth::new(8).unwrap(), &mut digit);
+		let rhs = SmallAPInt::new(BitWidth::new(8).unwrap(), Digit(7));
+		lhs.shl_inplace(&rhs).unwrap();
+		assert_eq!(lhs.digit().repr(), 0b1000_0000);
+	}
+
+	#[test]
+	fn shl_inplace_at_width_is_zero() {
+		let mut digit = Digit(0b0000_0011);
+		let mut lhs = SmallAPIntMut::new(BitWidth::new(8).unwrap(), &mut digit);
+		let rhs = SmallAPInt::new(BitWidth::new(8).unwrap(), Digit(8));
+		lhs.shl_inplace(&rhs).unwrap();
+		assert_eq!(lhs.digit().repr(), 0);
 	}
 
+	#[test]
+	fn shl_inplace_beyond_width_is_zero() {
+		let mut digit = Digit(0b0000_0011);
+		let mut lhs = SmallAPIntMut::new(BitWidth::new(8).unwrap(), &mut digit);
+		let rhs = SmallAPInt::new(BitWidth::new(8).unwrap(), Digit(20));
+		lhs.shl_inplace(&rhs).unwrap();
+		assert_eq!(lhs.digit().repr(), 0);
+	}
+
+	#[test]
+	fn lshr_inplace_beyond_width_is_zero() {
+		let mut digit = Digit(0b1111_1111);
+		let mut lhs = SmallAPIntMut::new(BitWidth::new(8).unwrap(), &mut digit);
+		let rhs = SmallAPInt::new(BitWidth::new(8).unwrap(), Digit(20));
+		lhs.lshr_inplace(&rhs).unwrap();
+		assert_eq!(lhs.digit().repr(), 0);
+	}
+
+	#[test]
+	fn ashr_inplace_sign_extends_negative() {
+		// -8 as an 8-bit two's-complement value is 0b1111_1000.
+		let mut digit = Digit(0b1111_1000);
+		let mut lhs = SmallAPIntMut::new(BitWidth::new(8).unwrap(), &mut digit);
+		let rhs = SmallAPInt::new(BitWidth::new(8).unwrap(), Digit(2));
+		lhs.ashr_inplace(&rhs).unwrap();
+		// -2 as an 8-bit two's-complement value is 0b1111_1110.
+		assert_eq!(lhs.digit().repr(), 0b1111_1110);
+	}
+
+	#[test]
+	fn ashr_inplace_beyond_width_saturates_by_sign() {
+		let mut digit = Digit(0b1111_1000);
+		let mut lhs = SmallAPIntMut::new(BitWidth::new(8).unwrap(), &mut digit);
+		let rhs = SmallAPInt::new(BitWidth::new(8).unwrap(), Digit(20));
+		lhs.ashr_inplace(&rhs).unwrap();
+		assert_eq!(lhs.digit().repr(), 0b1111_1111);
+
+		let mut digit = Digit(0b0111_1000);
+		let mut lhs = SmallAPIntMut::new(BitWidth::new(8).unwrap(), &mut digit);
+		lhs.ashr_inplace(&rhs).unwrap();
+		assert_eq!(lhs.digit().repr(), 0);
+	}
 }